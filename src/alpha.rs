@@ -1,4 +1,5 @@
 use std::fmt;
+use std::ops::{Deref, DerefMut};
 
 use num_traits::Float;
 
@@ -25,6 +26,57 @@ impl<C, A: Channel> Alpha<C, A> {
     }
 }
 
+/// Trait for color types without an alpha channel, letting one be attached
+///
+/// This is what lets `with_alpha` work on every color type without having to duplicate every
+/// space as a separate `*A` type.
+pub trait WithAlpha: Sized {
+    /// Attaches an alpha channel to this color, fully opaque alphas included
+    #[inline]
+    fn with_alpha<A: Channel>(self, alpha: A) -> Alpha<Self, A> {
+        Alpha::new(self, alpha)
+    }
+}
+
+impl<C: Color> WithAlpha for C {}
+
+/// Trait for color types that already carry an alpha channel
+pub trait HasAlpha {
+    /// This color's type without the alpha channel
+    type Color;
+    /// The type of the alpha channel
+    type Alpha;
+
+    /// Strips the alpha channel, discarding it
+    fn without_alpha(self) -> Self::Color;
+}
+
+impl<C, A> HasAlpha for Alpha<C, A> {
+    type Color = C;
+    type Alpha = A;
+
+    #[inline]
+    fn without_alpha(self) -> C {
+        self.color
+    }
+}
+
+impl<C, A> Deref for Alpha<C, A> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &C {
+        &self.color
+    }
+}
+
+impl<C, A> DerefMut for Alpha<C, A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.color
+    }
+}
+
 impl<C: Color, A: Channel> Color for Alpha<C, A> {
     fn normalize(self) -> Self {
         let color = self.color.normalize();
@@ -143,6 +195,132 @@ impl<T: Channel + Clone, S> From<&[T; 4]> for Alpha<RGBColor<T, S>, T> {
     }
 }
 
+impl<T> Alpha<RGBColor<T, LinearSpace>, T>
+where
+    T: Channel + std::ops::Mul<Output = T> + Clone,
+{
+    /// Scales the color channels by `alpha`, producing a premultiplied-alpha color
+    ///
+    /// Compositing operators want this form (see `blend`): blending two straight-alpha colors
+    /// independently of how much they'll end up contributing darkens the fringe between them.
+    pub fn premultiply(self) -> PremultipliedAlpha<RGBColor<T, LinearSpace>, T> {
+        let alpha = self.alpha;
+        PremultipliedAlpha::new(self.color * alpha.clone(), alpha)
+    }
+}
+
+/// A color with an alpha channel, stored premultiplied into the color channels
+///
+/// Alpha of 1 means the color is fully opaque, and alpha of 0 means it's fully transparent.
+///
+/// Unlike `Alpha`, the color channels here are already scaled by `alpha`. This is the form most
+/// compositing operators (and GPU texture formats) actually want to blend in; see `Alpha::premultiply`
+/// and the `blend` module.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PremultipliedAlpha<C, A> {
+    pub color: C,
+    pub alpha: A,
+}
+
+impl<C, A> PremultipliedAlpha<C, A> {
+    /// Creates a new premultiplied-alpha color from already-premultiplied channels
+    #[inline]
+    pub fn new(color: C, alpha: A) -> Self {
+        PremultipliedAlpha { color, alpha }
+    }
+}
+
+impl<T> PremultipliedAlpha<RGBColor<T, LinearSpace>, T>
+where
+    T: Channel + std::ops::Div<Output = T> + Clone,
+{
+    /// Divides the premultiplied color channels back out by `alpha`, returning straight alpha
+    ///
+    /// Guards against `alpha == 0`, returning zero channels instead of dividing by zero.
+    pub fn unmultiply(self) -> Alpha<RGBColor<T, LinearSpace>, T> {
+        let alpha = self.alpha;
+        let color = if alpha == T::ch_zero() {
+            RGBColor::default()
+        } else {
+            self.color / alpha.clone()
+        };
+        Alpha::new(color, alpha)
+    }
+}
+
+impl<S> Alpha<RGBColor<u8, S>, u8> {
+    /// Packs this color's channels into a single `u32`, in the given byte order
+    pub fn to_u32(self, order: PixelFormat) -> u32 {
+        let (r, g, b, a) = self.tuple();
+        match order {
+            PixelFormat::Argb => {
+                (u32::from(a) << 24) | (u32::from(r) << 16) | (u32::from(g) << 8) | u32::from(b)
+            }
+            PixelFormat::Rgba => {
+                (u32::from(r) << 24) | (u32::from(g) << 16) | (u32::from(b) << 8) | u32::from(a)
+            }
+        }
+    }
+
+    /// Unpacks a `u32` in the given byte order into a color with its alpha channel
+    pub fn from_u32(n: u32, order: PixelFormat) -> Self {
+        let (r, g, b, a) = match order {
+            PixelFormat::Argb => ((n >> 16) as u8, (n >> 8) as u8, n as u8, (n >> 24) as u8),
+            PixelFormat::Rgba => ((n >> 24) as u8, (n >> 16) as u8, (n >> 8) as u8, n as u8),
+        };
+        (r, g, b, a).into()
+    }
+
+    /// Packs this color into a `0xAARRGGBB` integer
+    #[inline]
+    pub fn to_u32_argb(self) -> u32 {
+        self.to_u32(PixelFormat::Argb)
+    }
+
+    /// Unpacks a color and its alpha from a `0xAARRGGBB` integer
+    #[inline]
+    pub fn from_u32_argb(n: u32) -> Self {
+        Self::from_u32(n, PixelFormat::Argb)
+    }
+
+    /// Packs this color into a `0xRRGGBBAA` integer
+    #[inline]
+    pub fn to_u32_rgba(self) -> u32 {
+        self.to_u32(PixelFormat::Rgba)
+    }
+
+    /// Unpacks a color and its alpha from a `0xRRGGBBAA` integer
+    #[inline]
+    pub fn from_u32_rgba(n: u32) -> Self {
+        Self::from_u32(n, PixelFormat::Rgba)
+    }
+
+    /// Parses a color with alpha from a `#RGB`, `#RRGGBB` or `#RRGGBBAA` hex string
+    ///
+    /// The leading `#` is optional. A color without an alpha component (`#RGB`/`#RRGGBB`) is
+    /// parsed as fully opaque.
+    pub fn from_hex<T: AsRef<str>>(hex_str: T) -> Result<Self, PaletteError> {
+        let text = hex_str.as_ref();
+        let invalid = || PaletteError::InvalidHex { text: text.into() };
+
+        let digits = text.strip_prefix('#').unwrap_or(text);
+
+        if digits.len() == 8 {
+            let color = RGBColor::from_hex(&digits[..6]).map_err(|_| invalid())?;
+            let alpha = u8::from_str_radix(&digits[6..8], 16).map_err(|_| invalid())?;
+            Ok(Alpha::new(color, alpha))
+        } else {
+            RGBColor::from_hex(digits).map(Alpha::<_, u8>::from)
+        }
+    }
+
+    /// Formats this color as a `#RRGGBBAA` hex string
+    #[inline]
+    pub fn to_hex_string(self) -> String {
+        format!("#{:X}", self)
+    }
+}
+
 impl<C: fmt::UpperHex> fmt::UpperHex for Alpha<C, u8> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:X}{:02X}", self.color, self.alpha)
@@ -37,3 +37,30 @@ impl Blend<LinRGB48Color> for LinRGB48Color {
         ).conv()
     }
 }
+
+impl AlphaBlend<PremultipliedAlpha<LinRGBColor, f32>> for PremultipliedAlpha<LinRGBColor, f32> {
+    /// Composites `foreground` over this color with the Porter-Duff "over" operator, in linear
+    /// space
+    ///
+    /// Both colors are already premultiplied, so this is the textbook `fg + bg * (1 - fg.alpha)`
+    /// with no division needed to keep the channels numerically correct.
+    fn alpha_blend(&self, foreground: &Self) -> Self {
+        let out_a = foreground.alpha + self.alpha * (1.0 - foreground.alpha);
+        let out_rgb = foreground.color + self.color * (1.0 - foreground.alpha);
+        PremultipliedAlpha::new(out_rgb, out_a)
+    }
+}
+
+impl AlphaBlend<Alpha<LinRGBColor, f32>> for Alpha<LinRGBColor, f32> {
+    /// Composites `foreground` over this color with the Porter-Duff "over" operator, in linear
+    /// space
+    ///
+    /// Both colors are treated as straight (unpremultiplied) alpha: this premultiplies both sides,
+    /// blends in premultiplied space where "over" is numerically correct, then unmultiplies the
+    /// result back to straight alpha.
+    fn alpha_blend(&self, foreground: &Self) -> Self {
+        self.premultiply()
+            .alpha_blend(&foreground.premultiply())
+            .unmultiply()
+    }
+}
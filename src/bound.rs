@@ -0,0 +1,79 @@
+//! Declared per-channel bounds for color types
+//!
+//! This turns the ranges that `normalize`/`is_normal` already enforce into queryable data, so
+//! generic code (clamping, random sampling, gradient interpolation) can work across any color
+//! type without hard-coding per-space limits.
+
+use crate::*;
+
+/// Trait for colors whose channels have fixed, statically-known bounds
+pub trait Bound {
+    /// The minimum and maximum of each channel, in this color's declaration order
+    fn bounds() -> [(f32, f32); 3];
+}
+
+impl<H: Channel, T: Channel, S> Bound for HSVColor<H, T, S> {
+    fn bounds() -> [(f32, f32); 3] {
+        [
+            (cuwtf(H::ch_zero()), cuwtf(H::ch_max())),
+            (cuwtf(T::ch_zero()), cuwtf(T::ch_max())),
+            (cuwtf(T::ch_zero()), cuwtf(T::ch_max())),
+        ]
+    }
+}
+
+impl<H: Channel, T: Channel, S> Bound for HSIColor<H, T, S> {
+    fn bounds() -> [(f32, f32); 3] {
+        [
+            (cuwtf(H::ch_zero()), cuwtf(H::ch_max())),
+            (cuwtf(T::ch_zero()), cuwtf(T::ch_max())),
+            (cuwtf(T::ch_zero()), cuwtf(T::ch_max())),
+        ]
+    }
+}
+
+impl<H: Channel, T: Channel, S> Bound for HSLColor<H, T, S> {
+    fn bounds() -> [(f32, f32); 3] {
+        [
+            (cuwtf(H::ch_zero()), cuwtf(H::ch_max())),
+            (cuwtf(T::ch_zero()), cuwtf(T::ch_max())),
+            (cuwtf(T::ch_zero()), cuwtf(T::ch_max())),
+        ]
+    }
+}
+
+impl<H: Channel, T: Channel, S> Bound for HWBColor<H, T, S> {
+    fn bounds() -> [(f32, f32); 3] {
+        [
+            (cuwtf(H::ch_zero()), cuwtf(H::ch_max())),
+            (cuwtf(T::ch_zero()), cuwtf(T::ch_max())),
+            (cuwtf(T::ch_zero()), cuwtf(T::ch_max())),
+        ]
+    }
+}
+
+impl<T: Channel, S> Bound for RGBColor<T, S> {
+    fn bounds() -> [(f32, f32); 3] {
+        let channel = (cuwtf(T::ch_zero()), cuwtf(T::ch_max()));
+        [channel, channel, channel]
+    }
+}
+
+impl<H: Channel> Bound for HSLuvColor<H> {
+    fn bounds() -> [(f32, f32); 3] {
+        [
+            (cuwtf(H::ch_zero()), cuwtf(H::ch_max())),
+            (0.0, 100.0),
+            (0.0, 100.0),
+        ]
+    }
+}
+
+impl<H: Channel> Bound for LChuvColor<H> {
+    /// The lightness and hue bounds are exact, but the chroma bound is only an upper bound on the
+    /// chroma reachable anywhere in the sRGB gamut, not a tight bound for this color's own `l`/`h` - use
+    /// `HSLuvColor::lchuv`'s `max_chroma` (via `LChuvColor::hsluv`) for an exact per-color bound.
+    fn bounds() -> [(f32, f32); 3] {
+        [(0.0, 100.0), (0.0, 180.0), (cuwtf(H::ch_zero()), cuwtf(H::ch_max()))]
+    }
+}
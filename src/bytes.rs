@@ -0,0 +1,55 @@
+//! Zero-copy byte views of color slices, for framebuffers and other streaming output
+//!
+//! Mirrors the split `bytemuck` uses between a marker trait and the actual reinterpret-cast, but
+//! without taking the dependency: `Pod` marks a color type as safe to view as raw bytes, and
+//! `as_bytes`/`as_bytes_mut`/`from_bytes` do the reinterpreting. This lets a `&[SRGB24Color]` be
+//! written straight to a `Write` implementor without copying each pixel out one at a time.
+
+use std::{mem, slice};
+
+use crate::*;
+
+/// Marker for color types that are safe to reinterpret as a contiguous byte buffer
+///
+/// # Safety
+/// Implementors must be `#[repr(C)]` (or `repr(transparent)`), contain no padding bytes, and have
+/// no bit pattern that is invalid for any of their fields.
+pub unsafe trait Pod: Copy + 'static {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for f32 {}
+
+unsafe impl<T: Pod, S: 'static> Pod for RGBColor<T, S> {}
+
+/// Reinterprets a slice of `Pod` colors as a contiguous slice of their raw bytes
+#[inline]
+pub fn as_bytes<T: Pod>(colors: &[T]) -> &[u8] {
+    unsafe {
+        slice::from_raw_parts(colors.as_ptr() as *const u8, mem::size_of_val(colors))
+    }
+}
+
+/// Reinterprets a mutable slice of `Pod` colors as a contiguous slice of their raw bytes
+#[inline]
+pub fn as_bytes_mut<T: Pod>(colors: &mut [T]) -> &mut [u8] {
+    unsafe {
+        slice::from_raw_parts_mut(colors.as_mut_ptr() as *mut u8, mem::size_of_val(colors))
+    }
+}
+
+/// Reinterprets a byte slice as a slice of `Pod` colors
+///
+/// Panics if `bytes`'s length isn't a multiple of `T`'s size, or if it isn't aligned for `T`.
+#[inline]
+pub fn from_bytes<T: Pod>(bytes: &[u8]) -> &[T] {
+    assert_eq!(bytes.len() % mem::size_of::<T>(), 0,
+               "byte slice length is not a multiple of the color size");
+    assert_eq!(bytes.as_ptr() as usize % mem::align_of::<T>(), 0,
+               "byte slice is not aligned for this color type");
+
+    unsafe {
+        slice::from_raw_parts(bytes.as_ptr() as *const T, bytes.len() / mem::size_of::<T>())
+    }
+}
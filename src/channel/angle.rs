@@ -14,7 +14,7 @@ use crate::{cuwf, cuwtf, Channel};
 ///
 /// Operations done for these types wrap into it's normal range, starting from 0 and ending in
 /// the value of full revolution (360° in degrees, 2π in radians).
-pub trait Angle: Sized {
+pub trait Angle: Sized + Add<Output = Self> {
     /// The inner type of this angle
     type Inner: PartialOrd + From<Self> + Into<Self> + NumCast + NumOps;
 
@@ -42,6 +42,32 @@ pub trait Angle: Sized {
             a.into()
         }
     }
+
+    /// Value of a half revolution (180° or π rad)
+    fn half_turn() -> Self {
+        let full: f32 = cuwtf(Into::<Self::Inner>::into(Self::full_angle()));
+        cuwf::<Self::Inner>(full / 2.0).into()
+    }
+
+    /// The angle on the opposite side of the wheel from this one: this angle plus a half turn
+    ///
+    /// For a hue, this is its complementary color.
+    fn opposite(self) -> Self {
+        (self + Self::half_turn()).wrap()
+    }
+}
+
+/// Converts an angle from one unit into another via a common "to revolutions" pivot
+///
+/// Divides `angle` by its unit's `full_angle`, then multiplies by the target unit's `full_angle`,
+/// so e.g. a half revolution in `Deg` lands at a half revolution in `Rad` regardless of how the
+/// two units scale. Used to implement the `From` conversions between `Deg`, `Rad` and `Rev`.
+fn convert_angle<A: Angle, B: Angle>(angle: A) -> B {
+    let from_full: f32 = cuwtf(Into::<A::Inner>::into(A::full_angle()));
+    let from_val: f32 = cuwtf(Into::<A::Inner>::into(angle));
+    let to_full: f32 = cuwtf(Into::<B::Inner>::into(B::full_angle()));
+    let to_val: B::Inner = cuwf(from_val / from_full * to_full);
+    to_val.into()
 }
 
 /// A wrapper type for angles in degrees
@@ -200,6 +226,24 @@ impl From<Rad> for f32 {
 
 generic_newtype_from_impls!(Rev, u8, u16, u32, f32);
 
+macro_rules! impl_angle_conversion {
+    ($from:ty => $to:ty) => {
+        impl From<$from> for $to {
+            /// Converts between angle units via the `convert_angle` "to revolutions" pivot
+            fn from(angle: $from) -> Self {
+                convert_angle::<$from, $to>(angle).wrap()
+            }
+        }
+    };
+}
+
+impl_angle_conversion!(Deg<f32> => Rad);
+impl_angle_conversion!(Rad => Deg<f32>);
+impl_angle_conversion!(Deg<f32> => Rev<f32>);
+impl_angle_conversion!(Rev<f32> => Deg<f32>);
+impl_angle_conversion!(Rad => Rev<f32>);
+impl_angle_conversion!(Rev<f32> => Rad);
+
 macro_rules! impl_newtype_ops {
     ( $struct_name:ident;
       $( $trait:ident, $fun:ident, $as_trait:ident, $as_fun:ident );*
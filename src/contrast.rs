@@ -0,0 +1,41 @@
+//! Generic luminance and contrast helpers, usable on any color reachable from the hub space
+//!
+//! `RGBColor<T, LinearSpace>` already has `relative_luminance`/`contrast_ratio`/`best_contrast`
+//! (see `rgb.rs`) since that's where the math is defined. This trait routes every other color
+//! type through `ToHub` first, so the same comparisons work on HSV, Lab and the rest without
+//! each one having to re-derive them.
+
+use crate::*;
+
+/// Trait for colors that can report their WCAG relative luminance and be ranked by contrast
+///
+/// Blanket-implemented for every color reachable from the hub conversion framework.
+pub trait Contrast: ToHub + Copy {
+    /// This color's relative luminance, per the WCAG definition
+    #[inline]
+    fn luma(self) -> f32 {
+        self.to_hub().relative_luminance()
+    }
+
+    /// The WCAG contrast ratio between this color and `other`
+    ///
+    /// Ranges from `1.0` (no contrast) to `21.0` (black against white), and is symmetric: the
+    /// lighter of the two colors is always used as the numerator.
+    #[inline]
+    fn wcag_contrast_ratio(self, other: Self) -> f32 {
+        self.to_hub().contrast_ratio(&other.to_hub())
+    }
+
+    /// Returns whichever of `a` or `b` has the highest contrast ratio against this color
+    ///
+    /// Handy for picking a readable text color over an arbitrary background.
+    fn best_contrast(self, a: Self, b: Self) -> Self {
+        if self.wcag_contrast_ratio(a) >= self.wcag_contrast_ratio(b) {
+            a
+        } else {
+            b
+        }
+    }
+}
+
+impl<C: ToHub + Copy> Contrast for C {}
@@ -0,0 +1,295 @@
+//! A uniform conversion framework between color types
+//!
+//! Instead of writing every `A -> B` conversion by hand, each color type only has to describe
+//! how to reach and leave the hub space (linear RGB, via [`ToHub`]/[`FromHub`]). [`FromColor`]
+//! and [`IntoColor`] then derive every other path as `A -> hub -> B`.
+//!
+//! Adding a new color space to the framework is just a matter of implementing `ToHub`/`FromHub`
+//! for it once, instead of one conversion per existing space.
+
+use num_traits::Float;
+
+use crate::*;
+
+/// Trait for converting a color from another color type, routing through the hub space
+///
+/// Mirrors `std::convert::From`.
+pub trait FromColor<C>: Sized {
+    /// Converts `color` into `Self`, normalizing the result
+    fn from_color(color: C) -> Self;
+
+    /// Same as `from_color`, but skips normalizing the result
+    ///
+    /// Useful for callers chaining several conversions together who only want to clamp once, at
+    /// the very end.
+    fn from_color_unclamped(color: C) -> Self;
+}
+
+/// Trait for converting a color into another color type, routing through the hub space
+///
+/// Mirrors `std::convert::Into`; blanket-implemented for every pair connected by `FromColor`.
+pub trait IntoColor<C> {
+    /// Converts `self` into `C`, normalizing the result
+    fn into_color(self) -> C;
+
+    /// Same as `into_color`, but skips normalizing the result
+    fn into_color_unclamped(self) -> C;
+}
+
+impl<C, D: FromColor<C>> IntoColor<D> for C {
+    #[inline]
+    fn into_color(self) -> D {
+        D::from_color(self)
+    }
+
+    #[inline]
+    fn into_color_unclamped(self) -> D {
+        D::from_color_unclamped(self)
+    }
+}
+
+/// Trait for colors that can reach the hub space (linear RGB)
+pub trait ToHub {
+    /// Converts this color into the hub space, normalizing it first
+    fn to_hub(self) -> LinRGBColor;
+
+    /// Same as `to_hub`, but without normalizing first
+    fn to_hub_unclamped(self) -> LinRGBColor;
+}
+
+/// Trait for colors that can be built from the hub space (linear RGB)
+pub trait FromHub: Sized {
+    /// Builds this color from the hub space, normalizing the result
+    fn from_hub(hub: LinRGBColor) -> Self;
+
+    /// Same as `from_hub`, but without normalizing the result
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self;
+}
+
+impl<C: ToHub, D: FromHub> FromColor<C> for D {
+    #[inline]
+    fn from_color(color: C) -> Self {
+        D::from_hub(color.to_hub())
+    }
+
+    #[inline]
+    fn from_color_unclamped(color: C) -> Self {
+        D::from_hub_unclamped(color.to_hub_unclamped())
+    }
+}
+
+impl<T: Channel + Float> ToHub for RGBColor<T, SRGBSpace> {
+    #[inline]
+    fn to_hub(self) -> LinRGBColor {
+        self.normalize().std_decode().conv()
+    }
+
+    #[inline]
+    fn to_hub_unclamped(self) -> LinRGBColor {
+        self.std_decode().conv()
+    }
+}
+
+impl<T: Channel + Float> FromHub for RGBColor<T, SRGBSpace> {
+    #[inline]
+    fn from_hub(hub: LinRGBColor) -> Self {
+        hub.conv::<T>().std_encode().normalize()
+    }
+
+    #[inline]
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self {
+        hub.conv::<T>().std_encode()
+    }
+}
+
+impl<T: Channel> ToHub for RGBColor<T, LinearSpace> {
+    #[inline]
+    fn to_hub(self) -> LinRGBColor {
+        self.normalize().conv()
+    }
+
+    #[inline]
+    fn to_hub_unclamped(self) -> LinRGBColor {
+        self.conv()
+    }
+}
+
+impl<T: Channel> FromHub for RGBColor<T, LinearSpace> {
+    #[inline]
+    fn from_hub(hub: LinRGBColor) -> Self {
+        hub.conv::<T>().normalize()
+    }
+
+    #[inline]
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self {
+        hub.conv::<T>()
+    }
+}
+
+impl<H: Channel, T: Channel + Float> ToHub for HSVColor<H, T, SRGBSpace> {
+    #[inline]
+    fn to_hub(self) -> LinRGBColor {
+        self.normalize().rgb().to_hub()
+    }
+
+    #[inline]
+    fn to_hub_unclamped(self) -> LinRGBColor {
+        self.rgb().to_hub_unclamped()
+    }
+}
+
+impl<H: Channel, T: Channel + Float> FromHub for HSVColor<H, T, SRGBSpace> {
+    #[inline]
+    fn from_hub(hub: LinRGBColor) -> Self {
+        RGBColor::<T, SRGBSpace>::from_hub(hub).hsv()
+    }
+
+    #[inline]
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self {
+        RGBColor::<T, SRGBSpace>::from_hub_unclamped(hub).hsv()
+    }
+}
+
+impl<H: Channel, T: Channel + Float> ToHub for HSVColor<H, T, LinearSpace> {
+    #[inline]
+    fn to_hub(self) -> LinRGBColor {
+        self.normalize().rgb().to_hub()
+    }
+
+    #[inline]
+    fn to_hub_unclamped(self) -> LinRGBColor {
+        self.rgb().to_hub_unclamped()
+    }
+}
+
+impl<H: Channel, T: Channel + Float> FromHub for HSVColor<H, T, LinearSpace> {
+    #[inline]
+    fn from_hub(hub: LinRGBColor) -> Self {
+        RGBColor::<T, LinearSpace>::from_hub(hub).hsv()
+    }
+
+    #[inline]
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self {
+        RGBColor::<T, LinearSpace>::from_hub_unclamped(hub).hsv()
+    }
+}
+
+impl ToHub for XYZColor {
+    #[inline]
+    fn to_hub(self) -> LinRGBColor {
+        self.normalize().rgb()
+    }
+
+    #[inline]
+    fn to_hub_unclamped(self) -> LinRGBColor {
+        self.rgb()
+    }
+}
+
+impl FromHub for XYZColor {
+    #[inline]
+    fn from_hub(hub: LinRGBColor) -> Self {
+        XYZColor::from(hub).normalize()
+    }
+
+    #[inline]
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self {
+        XYZColor::from(hub)
+    }
+}
+
+impl ToHub for LabColor {
+    #[inline]
+    fn to_hub(self) -> LinRGBColor {
+        self.normalize().rgb()
+    }
+
+    #[inline]
+    fn to_hub_unclamped(self) -> LinRGBColor {
+        self.rgb()
+    }
+}
+
+impl FromHub for LabColor {
+    #[inline]
+    fn from_hub(hub: LinRGBColor) -> Self {
+        LabColor::from(hub).normalize()
+    }
+
+    #[inline]
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self {
+        LabColor::from(hub)
+    }
+}
+
+impl<H: Channel, T: Channel + Float> ToHub for HWBColor<H, T, SRGBSpace> {
+    #[inline]
+    fn to_hub(self) -> LinRGBColor {
+        self.normalize().rgb().to_hub()
+    }
+
+    #[inline]
+    fn to_hub_unclamped(self) -> LinRGBColor {
+        self.rgb().to_hub_unclamped()
+    }
+}
+
+impl<H: Channel, T: Channel + Float> FromHub for HWBColor<H, T, SRGBSpace> {
+    #[inline]
+    fn from_hub(hub: LinRGBColor) -> Self {
+        RGBColor::<T, SRGBSpace>::from_hub(hub).hwb()
+    }
+
+    #[inline]
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self {
+        RGBColor::<T, SRGBSpace>::from_hub_unclamped(hub).hwb()
+    }
+}
+
+impl<H: Channel, T: Channel + Float> ToHub for HWBColor<H, T, LinearSpace> {
+    #[inline]
+    fn to_hub(self) -> LinRGBColor {
+        self.normalize().rgb().to_hub()
+    }
+
+    #[inline]
+    fn to_hub_unclamped(self) -> LinRGBColor {
+        self.rgb().to_hub_unclamped()
+    }
+}
+
+impl<H: Channel, T: Channel + Float> FromHub for HWBColor<H, T, LinearSpace> {
+    #[inline]
+    fn from_hub(hub: LinRGBColor) -> Self {
+        RGBColor::<T, LinearSpace>::from_hub(hub).hwb()
+    }
+
+    #[inline]
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self {
+        RGBColor::<T, LinearSpace>::from_hub_unclamped(hub).hwb()
+    }
+}
+
+impl<H: Channel> ToHub for LchColor<H> {
+    #[inline]
+    fn to_hub(self) -> LinRGBColor {
+        self.normalize().rgb()
+    }
+
+    #[inline]
+    fn to_hub_unclamped(self) -> LinRGBColor {
+        self.rgb()
+    }
+}
+
+impl<H: Channel> FromHub for LchColor<H> {
+    #[inline]
+    fn from_hub(hub: LinRGBColor) -> Self {
+        LchColor::from(hub).normalize()
+    }
+
+    #[inline]
+    fn from_hub_unclamped(hub: LinRGBColor) -> Self {
+        LchColor::from(hub)
+    }
+}
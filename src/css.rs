@@ -0,0 +1,174 @@
+//! A `FromStr` parser for the common CSS color syntaxes
+//!
+//! Understands `#rgb`/`#rgba`/`#rrggbb`/`#rrggbbaa` hex, the `rgb()`/`rgba()`/`hsl()`/`hsla()`
+//! functional notations (numbers or percentages) and the CSS/X11 named-color keywords - a safe,
+//! descriptive-error alternative to hand-rolling a parser around `SRGB24Color::from_hex_unchecked`.
+//!
+//! `SRGB24Color::from_str` discards any alpha component; `Alpha<RGBColor<u8, S>, u8>::from_str`
+//! keeps it, defaulting to fully opaque for syntaxes that don't carry one.
+
+use std::str::FromStr;
+
+use crate::*;
+
+// a selection of the CSS/X11 named-color keywords, lowercase
+const NAMED_COLORS: &[(&str, (u8, u8, u8))] = &[
+    ("black", (0x00, 0x00, 0x00)),
+    ("white", (0xFF, 0xFF, 0xFF)),
+    ("red", (0xFF, 0x00, 0x00)),
+    ("lime", (0x00, 0xFF, 0x00)),
+    ("green", (0x00, 0x80, 0x00)),
+    ("blue", (0x00, 0x00, 0xFF)),
+    ("yellow", (0xFF, 0xFF, 0x00)),
+    ("cyan", (0x00, 0xFF, 0xFF)),
+    ("aqua", (0x00, 0xFF, 0xFF)),
+    ("magenta", (0xFF, 0x00, 0xFF)),
+    ("fuchsia", (0xFF, 0x00, 0xFF)),
+    ("gray", (0x80, 0x80, 0x80)),
+    ("grey", (0x80, 0x80, 0x80)),
+    ("silver", (0xC0, 0xC0, 0xC0)),
+    ("maroon", (0x80, 0x00, 0x00)),
+    ("olive", (0x80, 0x80, 0x00)),
+    ("navy", (0x00, 0x00, 0x80)),
+    ("teal", (0x00, 0x80, 0x80)),
+    ("purple", (0x80, 0x00, 0x80)),
+    ("orange", (0xFF, 0xA5, 0x00)),
+    ("pink", (0xFF, 0xC0, 0xCB)),
+    ("brown", (0xA5, 0x2A, 0x2A)),
+    ("gold", (0xFF, 0xD7, 0x00)),
+    ("indigo", (0x4B, 0x00, 0x82)),
+    ("violet", (0xEE, 0x82, 0xEE)),
+    ("coral", (0xFF, 0x7F, 0x50)),
+    ("salmon", (0xFA, 0x80, 0x72)),
+    ("khaki", (0xF0, 0xE6, 0x8C)),
+    ("turquoise", (0x40, 0xE0, 0xD0)),
+    ("chocolate", (0xD2, 0x69, 0x1E)),
+    ("crimson", (0xDC, 0x14, 0x3C)),
+    ("orchid", (0xDA, 0x70, 0xD6)),
+    ("plum", (0xDD, 0xA0, 0xDD)),
+    ("tan", (0xD2, 0xB4, 0x8C)),
+    ("beige", (0xF5, 0xF5, 0xDC)),
+    ("ivory", (0xFF, 0xFF, 0xF0)),
+    ("lavender", (0xE6, 0xE6, 0xFA)),
+    ("skyblue", (0x87, 0xCE, 0xEB)),
+    ("slategray", (0x70, 0x80, 0x90)),
+    ("tomato", (0xFF, 0x63, 0x47)),
+    ("rebeccapurple", (0x66, 0x33, 0x99)),
+    ("transparent", (0x00, 0x00, 0x00)),
+];
+
+fn invalid(text: &str) -> PaletteError {
+    PaletteError::InvalidColorString { text: text.into() }
+}
+
+/// Parses a single `rgb()`/`hsl()` component, accepting either a plain number or a percentage
+fn component(s: &str, max: f32) -> Option<f32> {
+    let s = s.trim();
+    if let Some(pct) = s.strip_suffix('%') {
+        Some(pct.trim().parse::<f32>().ok()? / 100.0 * max)
+    } else {
+        s.parse::<f32>().ok()
+    }
+}
+
+/// Parses the comma- or space-separated arguments out of a `name(...)` functional CSS notation
+fn func_args<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    let text = text.trim();
+    let body = text.strip_prefix(name)?.trim_start();
+    let body = body.strip_prefix('(')?;
+    body.strip_suffix(')')
+}
+
+/// Parses the `rgb()`/`rgba()` functional notations, defaulting alpha to fully opaque
+fn parse_rgb_fn(text: &str) -> Option<(u8, u8, u8, u8)> {
+    let args = func_args(text, "rgba").or_else(|| func_args(text, "rgb"))?;
+    let mut parts = args.split(|c| c == ',' || c == ' ').filter(|s| !s.is_empty());
+
+    let r = component(parts.next()?, 255.0)?;
+    let g = component(parts.next()?, 255.0)?;
+    let b = component(parts.next()?, 255.0)?;
+    let a = match parts.next() {
+        Some(a) => component(a, 1.0)? * 255.0,
+        None => 255.0,
+    };
+
+    Some((r as u8, g as u8, b as u8, a as u8))
+}
+
+/// Parses the `hsl()`/`hsla()` functional notations, defaulting alpha to fully opaque
+fn parse_hsl_fn(text: &str) -> Option<(u8, u8, u8, u8)> {
+    let args = func_args(text, "hsla").or_else(|| func_args(text, "hsl"))?;
+    let mut parts = args.split(|c| c == ',' || c == ' ').filter(|s| !s.is_empty());
+
+    let h = parts.next()?.trim().parse::<f32>().ok()?;
+    let s = component(parts.next()?, 1.0)?;
+    let l = component(parts.next()?, 1.0)?;
+    let a = match parts.next() {
+        Some(a) => component(a, 1.0)? * 255.0,
+        None => 255.0,
+    };
+
+    let (r, g, b) = HSLColor::<Deg<f32>, f32, SRGBSpace>::new(Deg(h), s, l)
+        .rgb()
+        .conv::<u8>()
+        .tuple();
+    Some((r, g, b, a as u8))
+}
+
+/// Parses `#rgb`/`#rgba`/`#rrggbb`/`#rrggbbaa` hex digits (without the leading `#`)
+fn parse_hex(digits: &str) -> Option<(u8, u8, u8, u8)> {
+    let (rgb_digits, a) = match digits.len() {
+        3 | 6 => (digits, None),
+        4 => (&digits[..3], Some(&digits[3..4])),
+        8 => (&digits[..6], Some(&digits[6..8])),
+        _ => return None,
+    };
+
+    let (r, g, b) = RGBColor::<u8, SRGBSpace>::from_hex(rgb_digits).ok()?.tuple();
+    let a = match a {
+        Some(a) if a.len() == 1 => u8::from_str_radix(&a.repeat(2), 16).ok()?,
+        Some(a) => u8::from_str_radix(a, 16).ok()?,
+        None => 255,
+    };
+
+    Some((r, g, b, a))
+}
+
+/// Parses any of the supported CSS color syntaxes into an `(r, g, b, a)` byte tuple
+fn parse_rgba(text: &str) -> Option<(u8, u8, u8, u8)> {
+    let trimmed = text.trim();
+    let lower = trimmed.to_ascii_lowercase();
+
+    if let Some(digits) = trimmed.strip_prefix('#') {
+        parse_hex(digits)
+    } else if lower.starts_with("rgb") {
+        parse_rgb_fn(&lower)
+    } else if lower.starts_with("hsl") {
+        parse_hsl_fn(&lower)
+    } else {
+        NAMED_COLORS
+            .iter()
+            .find(|(name, _)| *name == lower)
+            .map(|&(name, (r, g, b))| (r, g, b, if name == "transparent" { 0 } else { 255 }))
+    }
+}
+
+impl FromStr for SRGB24Color {
+    type Err = PaletteError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_rgba(text)
+            .map(|(r, g, b, _)| SRGB24Color::new(r, g, b))
+            .ok_or_else(|| invalid(text))
+    }
+}
+
+impl<S> FromStr for Alpha<RGBColor<u8, S>, u8> {
+    type Err = PaletteError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_rgba(text)
+            .map(|(r, g, b, a)| Alpha::new(RGBColor::new(r, g, b), a))
+            .ok_or_else(|| invalid(text))
+    }
+}
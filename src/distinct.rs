@@ -0,0 +1,61 @@
+//! Generates sets of maximally-distinguishable colors
+//!
+//! Builds on the Lab/CIEDE2000 subsystem: candidates are sampled from a hue/lightness grid, then
+//! a farthest-point placement greedily takes whichever remaining candidate has the largest
+//! minimum `delta_e` to every color already chosen.
+
+use crate::*;
+
+const HUE_STEPS: usize = 36;
+const LIGHTNESS_STEPS: &[f32] = &[35.0, 50.0, 65.0];
+const SATURATION: f32 = 0.85;
+
+/// Generates up to `n` maximally-distinguishable sRGB colors, for charts, tags or terminal output
+///
+/// Unlike `shades`, which only classifies an existing color, this builds an actual palette whose
+/// members are guaranteed to be visually separated from one another.
+///
+/// Candidates are drawn from a grid over hue and lightness with saturation fixed high enough to
+/// stay distinct without drifting into washed-out pastels. If `n` is larger than the number of
+/// sampled candidates, only that many distinct colors are returned.
+pub fn distinct_colors(n: usize) -> Vec<SRGB24Color> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let candidates: Vec<(SRGB24Color, LabColor)> = LIGHTNESS_STEPS
+        .iter()
+        .flat_map(|&l| {
+            (0..HUE_STEPS).map(move |i| {
+                let hue = i as f32 * (360.0 / HUE_STEPS as f32);
+                let rgb: SRGB24Color = HSLColor::new(Deg(hue), SATURATION, l / 100.0).rgb().conv();
+                let lab = LabColor::from(rgb.conv::<f32>().std_decode());
+                (rgb, lab)
+            })
+        })
+        .collect();
+
+    let mut result = Vec::with_capacity(n);
+    let mut chosen = Vec::with_capacity(n);
+
+    let &(first_rgb, first_lab) = &candidates[0];
+    result.push(first_rgb);
+    chosen.push(first_lab);
+
+    while result.len() < n && result.len() < candidates.len() {
+        let &(rgb, lab) = candidates
+            .iter()
+            .filter(|(rgb, _)| !result.contains(rgb))
+            .max_by(|(_, a), (_, b)| {
+                let min_a = chosen.iter().map(|c: &LabColor| c.delta_e(a)).fold(f32::INFINITY, f32::min);
+                let min_b = chosen.iter().map(|c: &LabColor| c.delta_e(b)).fold(f32::INFINITY, f32::min);
+                min_a.partial_cmp(&min_b).unwrap()
+            })
+            .unwrap();
+
+        result.push(rgb);
+        chosen.push(lab);
+    }
+
+    result
+}
@@ -1,5 +1,7 @@
 use std::io;
 
+use failure::Fail;
+
 #[derive(Debug, Fail)]
 pub enum PaletteError {
     #[fail(display = "Color `{}` declared without any color set!", name)]
@@ -7,7 +9,15 @@ pub enum PaletteError {
         name: Box<str>
     },
     #[fail(display = "IO error: {}", inner)]
-    IO { inner: io::Error }
+    IO { inner: io::Error },
+    #[fail(display = "Invalid hex color string: `{}`", text)]
+    InvalidHex {
+        text: Box<str>
+    },
+    #[fail(display = "Invalid color string: `{}`", text)]
+    InvalidColorString {
+        text: Box<str>
+    },
 }
 
 impl From<io::Error> for PaletteError {
@@ -0,0 +1,160 @@
+//! An N-stop color gradient, generalizing the two-color `Blend` trait
+//!
+//! Unlike `Blend`, which only ever mixes two colors by a fixed ratio, a `Gradient` holds any
+//! number of `(position, color)` stops and interpolates between its neighboring stops at any `t`.
+//! Which space that interpolation happens in - linear RGB, HSV/HSL (by hue's shorter arc) or Lab
+//! - is chosen by which color type the gradient is built over, since each gives a visibly
+//! different ramp.
+
+use num_traits::Float;
+
+use crate::*;
+
+/// Trait for colors that can be linearly interpolated between two values
+pub trait Lerp: Sized {
+    /// Interpolates between `self` and `other` by `t`
+    ///
+    /// `t = 0.0` returns `self`, `t = 1.0` returns `other`. For `t` outside `[0, 1]` the result is
+    /// extrapolated, but types with bounded channels clamp or normalize it back into their range;
+    /// only `LabColor` keeps the extrapolated values as they are.
+    fn lerp(self, other: Self, t: f32) -> Self;
+}
+
+impl<T: Channel + Float> Lerp for RGBColor<T, LinearSpace> {
+    /// Interpolates each channel independently, in linear space
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let t = cuwf::<T>(t);
+        self * (cuwf::<T>(1.0) - t) + other * t
+    }
+}
+
+impl<T: Channel + Float> Lerp for RGBColor<T, SRGBSpace> {
+    /// Interpolates in linear RGB and re-encodes
+    ///
+    /// Blending gamma-encoded sRGB channels directly darkens the midpoint relative to how the
+    /// eye perceives it, so this routes through the hub space instead of lerping `self`/`other`
+    /// in place.
+    fn lerp(self, other: Self, t: f32) -> Self {
+        RGBColor::from_hub(self.to_hub().lerp(other.to_hub(), t))
+    }
+}
+
+impl<T: Channel + Float, S> Lerp for HSVColor<Deg<f32>, T, S> {
+    /// Interpolates saturation and value linearly, and hue along its shorter arc
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (h1, s1, v1) = self.tuple();
+        let (h2, s2, v2) = other.tuple();
+
+        let delta = ((h2.0 - h1.0 + 540.0).rem_euclid(360.0)) - 180.0;
+        let h = Deg(h1.0 + delta * t).wrap();
+        let (s1, s2, v1, v2) = (cuwtf(s1), cuwtf(s2), cuwtf(v1), cuwtf(v2));
+
+        HSVColor::new(h, cuwf(s1 + (s2 - s1) * t), cuwf(v1 + (v2 - v1) * t))
+    }
+}
+
+impl<T: Channel + Float, S> Lerp for HSLColor<Deg<f32>, T, S> {
+    /// Interpolates saturation and lightness linearly, and hue along its shorter arc
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (h1, s1, l1) = self.tuple();
+        let (h2, s2, l2) = other.tuple();
+
+        let delta = ((h2.0 - h1.0 + 540.0).rem_euclid(360.0)) - 180.0;
+        let h = Deg(h1.0 + delta * t).wrap();
+        let (s1, s2, l1, l2) = (cuwtf(s1), cuwtf(s2), cuwtf(l1), cuwtf(l2));
+
+        HSLColor::new(h, cuwf(s1 + (s2 - s1) * t), cuwf(l1 + (l2 - l1) * t))
+    }
+}
+
+impl<C: Lerp, A: Channel + Float> Lerp for Alpha<C, A> {
+    /// Interpolates the color and the alpha channel independently, both linearly in `t`
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (a1, a2) = (cuwtf(self.alpha), cuwtf(other.alpha));
+        Alpha::new(self.color.lerp(other.color, t), cuwf(a1 + (a2 - a1) * t))
+    }
+}
+
+impl Lerp for LabColor {
+    /// Interpolates `l`, `a` and `b` linearly, for a perceptually even ramp
+    fn lerp(self, other: Self, t: f32) -> Self {
+        LabColor::new(
+            self.l + (other.l - self.l) * t,
+            self.a + (other.a - self.a) * t,
+            self.b + (other.b - self.b) * t,
+        )
+    }
+}
+
+/// A gradient built from a sequence of `(position, color)` stops
+///
+/// Positions need not be `[0, 1]` or evenly spaced; they're just sorted and interpolated between.
+pub struct Gradient<C> {
+    stops: Vec<(f32, C)>,
+}
+
+impl<C: Copy + Lerp> Gradient<C> {
+    /// Creates a new gradient from the given stops
+    ///
+    /// The stops are sorted by position; at least one stop is required.
+    pub fn new(mut stops: Vec<(f32, C)>) -> Self {
+        assert!(!stops.is_empty(), "a gradient needs at least one stop");
+        stops.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+        Gradient { stops }
+    }
+
+    /// Returns the color at position `t`, interpolating between its neighboring stops
+    ///
+    /// `t` before the first stop or after the last is clamped to that stop's color.
+    pub fn at(&self, t: f32) -> C {
+        let (first_pos, first_color) = self.stops[0];
+        if t <= first_pos {
+            return first_color;
+        }
+
+        let (last_pos, last_color) = *self.stops.last().unwrap();
+        if t >= last_pos {
+            return last_color;
+        }
+
+        let i = self.stops.windows(2).position(|w| t < w[1].0).unwrap();
+        let (pos0, color0) = self.stops[i];
+        let (pos1, color1) = self.stops[i + 1];
+
+        color0.lerp(color1, (t - pos0) / (pos1 - pos0))
+    }
+
+    /// Alias of `at`, matching the more common gradient-library naming
+    #[inline]
+    pub fn sample(&self, t: f32) -> C {
+        self.at(t)
+    }
+
+    /// Returns `n` colors evenly sampled between the gradient's first and last stop positions
+    pub fn iter(&self, n: usize) -> impl Iterator<Item = C> + '_ {
+        let (start, end) = (self.stops[0].0, self.stops.last().unwrap().0);
+        (0..n).map(move |i| {
+            let t = if n <= 1 {
+                start
+            } else {
+                start + (end - start) * (i as f32) / ((n - 1) as f32)
+            };
+            self.at(t)
+        })
+    }
+
+    /// Alias of `iter`, for callers building frame-interpolation style ramps
+    pub fn take(&self, n: usize) -> impl Iterator<Item = C> + '_ {
+        self.iter(n)
+    }
+}
+
+impl Gradient<LinRGBColor> {
+    /// Samples `n` evenly spaced stops and gamma-encodes each one to `SRGB24Color`
+    ///
+    /// Convenience for writing ramps out to a terminal or file, since the gradient itself always
+    /// interpolates in linear space but most output destinations expect sRGB.
+    pub fn take_srgb24(&self, n: usize) -> impl Iterator<Item = SRGB24Color> + '_ {
+        self.iter(n).map(|c| c.std_encode().conv())
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::*;
+
+/// An HSI color: hue, saturation and intensity
+///
+/// Unlike HSV's value channel, intensity is the mean of the RGB channels rather than their max,
+/// which makes HSI -> RGB prone to genuine out-of-gamut results; see `rgb_with`.
+///
+/// ## Type arguments
+/// `H` is the type of hue channel, `T` is the type of the saturation and intensity channels.
+///
+/// `S` is this color's colorspace.
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct HSIColor<H, T, S> {
+    pub h: H,
+    pub s: T,
+    pub i: T,
+    _space: PhantomData<S>,
+}
+
+impl<H, T, S> HSIColor<H, T, S> {
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (H, T, T) {
+        (self.h, self.s, self.i)
+    }
+}
+
+impl<H, T, S> HSIColor<H, T, S>
+where
+    Self: Color,
+{
+    /// Create a new HSI value.
+    ///
+    /// The value is normalized on creation.
+    pub fn new<H2: Into<H>>(h: H2, s: T, i: T) -> Self {
+        HSIColor {
+            h: h.into(),
+            s,
+            i,
+            _space: PhantomData,
+        }
+        .normalize()
+    }
+}
+
+impl<H: Channel, T: Channel, S> HSIColor<H, T, S> {
+    /// Transform this color into RGB form, clipping any out-of-gamut channels
+    ///
+    /// Equivalent to `rgb_with(GamutMode::Clip)`.
+    #[inline]
+    pub fn rgb(self) -> RGBColor<T, S> {
+        self.rgb_with(GamutMode::Clip)
+    }
+
+    /// Transform this color into RGB form, handling out-of-gamut channels with `mode`
+    ///
+    /// `GamutMode::Rescale` here first shifts all three channels up by the most negative one, if
+    /// any, then divides them by their resulting range when it exceeds `1`. For a normalized color
+    /// no channel is negative, so this just divides by the largest channel.
+    pub fn rgb_with(self, mode: GamutMode) -> RGBColor<T, S> {
+        let hue = cuwtf(self.h.conv::<Deg<f32>>().wrap());
+        let (s, i) = (cuwtf(self.s), cuwtf(self.i));
+
+        // the sector's hue, relative to its own 0 degree mark
+        let h = (hue % 120.0).to_radians();
+        let bright = i * (1.0 + s * h.cos() / (60.0f32.to_radians() - h).cos());
+        let dark = i * (1.0 - s);
+        let mid = 3.0 * i - (bright + dark);
+
+        let (r, g, b) = match (hue / 120.0) as u8 % 3 {
+            0 => (bright, mid, dark),
+            1 => (dark, bright, mid),
+            _ => (mid, dark, bright),
+        };
+
+        match mode {
+            GamutMode::Clip => (cuwf::<T>(r), cuwf::<T>(g), cuwf::<T>(b)).into(),
+            GamutMode::Preserve => RGBColor::raw(cuwf::<T>(r), cuwf::<T>(g), cuwf::<T>(b)),
+            GamutMode::Rescale => {
+                let max = r.max(g).max(b).max(1.0);
+                let min = r.min(g).min(b).min(0.0);
+                let range = max - min;
+                RGBColor::raw(
+                    cuwf::<T>((r - min) / range),
+                    cuwf::<T>((g - min) / range),
+                    cuwf::<T>((b - min) / range),
+                )
+            }
+        }
+    }
+
+    /// Converts the channels of this color into another type
+    #[inline]
+    pub fn conv<H2: Channel, T2: Channel>(self) -> HSIColor<H2, T2, S> {
+        HSIColor {
+            h: self.h.conv(),
+            s: self.s.conv(),
+            i: self.i.conv(),
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<H: Channel, T: Channel, S> Color for HSIColor<H, T, S> {
+    /// Normalize the color's values by normalizing the hue and zeroing the unnecessary channels
+    ///
+    /// If intensity channel is zero, black is returned.
+    /// If saturation channel is zero, hue is set to zero.
+    fn normalize(self) -> Self {
+        let (h, s, i) = self.tuple();
+        if i == T::ch_zero() {
+            Self::default()
+        } else if s == T::ch_zero() {
+            HSIColor {
+                h: H::ch_zero(),
+                s: T::ch_zero(),
+                i: i.clamp(),
+                _space: PhantomData,
+            }
+        } else {
+            HSIColor {
+                h: h.clamp(),
+                s: s.clamp(),
+                i: i.clamp(),
+                _space: PhantomData,
+            }
+        }
+    }
+
+    fn is_normal(&self) -> bool {
+        let (h, s, i) = (&self.h, &self.s, &self.i);
+        let (h0, t0) = (H::ch_zero(), T::ch_zero());
+
+        if !h.in_range() || !s.in_range() || !i.in_range() {
+            false
+        } else if *i == t0 {
+            *h == h0 && *s == t0
+        } else if *s == t0 {
+            *h == h0
+        } else {
+            true
+        }
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HSIColor<H, T, SRGBSpace>
+where
+    Self: Color,
+{
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        use self::BaseColor::*;
+
+        let f = |h: f32, s: f32, i: f32| Self::new(Deg(h).conv::<H>(), s.conv(), i.conv());
+
+        match base_color {
+            Black => f(0.0, 0.0, 0.0),
+            Grey => f(0.0, 0.0, 0.5),
+            White => f(0.0, 0.0, 1.0),
+            Red => f(0.0, 1.0, 1.0 / 3.0),
+            Yellow => f(60.0, 1.0, 2.0 / 3.0),
+            Green => f(120.0, 1.0, 1.0 / 3.0),
+            Cyan => f(180.0, 1.0, 2.0 / 3.0),
+            Blue => f(240.0, 1.0, 1.0 / 3.0),
+            Magenta => f(300.0, 1.0, 2.0 / 3.0),
+        }
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HSIColor<H, T, LinearSpace>
+where
+    Self: Color,
+{
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        RGBColor::<T, LinearSpace>::from(base_color).into()
+    }
+}
+
+impl<H: Channel, T: Channel, S> From<RGBColor<T, S>> for HSIColor<H, T, S>
+where
+    Self: Color,
+{
+    fn from(rgb: RGBColor<T, S>) -> Self {
+        let (r, g, b) = rgb.map(Channel::conv::<f32>).tuple();
+
+        let i = (r + g + b) / 3.0;
+        let min = r.min(g).min(b);
+        let s = if i == 0.0 { 0.0 } else { 1.0 - min / i };
+
+        let hue = if s == 0.0 {
+            0.0
+        } else {
+            let num = 0.5 * ((r - g) + (r - b));
+            let den = ((r - g).powi(2) + (r - b) * (g - b)).sqrt();
+            let theta = (num / den).max(-1.0).min(1.0).acos().to_degrees();
+            if b > g { 360.0 - theta } else { theta }
+        };
+
+        Self::new(Deg(hue).conv::<H>(), s.conv(), i.conv())
+    }
+}
+
+impl<H: Channel, T: Channel, S> Default for HSIColor<H, T, S> {
+    fn default() -> Self {
+        HSIColor {
+            h: H::ch_zero(),
+            s: T::ch_zero(),
+            i: T::ch_zero(),
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<H: Clone, T: Clone, S> Clone for HSIColor<H, T, S> {
+    fn clone(&self) -> Self {
+        HSIColor {
+            h: self.h.clone(),
+            s: self.s.clone(),
+            i: self.i.clone(),
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<H: Copy, T: Copy, S> Copy for HSIColor<H, T, S> {}
+
+impl<S> fmt::Display for HSIColor<f32, f32, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>5.1}°,{:>5.1}%,{:>5.1}%",
+            self.h,
+            self.s * 100.0,
+            self.i * 100.0
+        )
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::*;
+
+/// An HSL color: hue, saturation and lightness
+///
+/// Unlike HSV's value channel, lightness puts full saturation at `l = 0.5`, fading to black at
+/// `l = 0` and white at `l = 1` regardless of hue or saturation.
+///
+/// ## Type arguments
+/// `H` is the type of hue channel, `T` is the type of the saturation and lightness channels.
+///
+/// `S` is this color's colorspace.
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct HSLColor<H, T, S> {
+    pub h: H,
+    pub s: T,
+    pub l: T,
+    _space: PhantomData<S>,
+}
+
+impl<H, T, S> HSLColor<H, T, S> {
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (H, T, T) {
+        (self.h, self.s, self.l)
+    }
+}
+
+impl<H, T, S> HSLColor<H, T, S>
+where
+    Self: Color,
+{
+    /// Create a new HSL value.
+    ///
+    /// The value is normalized on creation.
+    pub fn new<H2: Into<H>>(h: H2, s: T, l: T) -> Self {
+        HSLColor {
+            h: h.into(),
+            s,
+            l,
+            _space: PhantomData,
+        }
+        .normalize()
+    }
+}
+
+impl<H: Channel, T: Channel, S> HSLColor<H, T, S> {
+    /// Transform this color into RGB form, clipping any out-of-range channels
+    ///
+    /// This should be done to a normalized HSL color. Equivalent to
+    /// `rgb_with(GamutMode::Clip)`.
+    #[inline]
+    pub fn rgb(self) -> RGBColor<T, S> {
+        self.rgb_with(GamutMode::Clip)
+    }
+
+    /// Transform this color into RGB form, handling out-of-range channels with `mode`
+    ///
+    /// The hue is wrapped into its normal range before conversion, so this never panics even on
+    /// a non-normalized color.
+    pub fn rgb_with(self, mode: GamutMode) -> RGBColor<T, S> {
+        let h = cuwtf(self.h.conv::<Deg<f32>>().wrap()) / 60.0;
+        let (s, l) = (cuwtf(self.s), cuwtf(self.l));
+
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let m = l - c / 2.0;
+
+        let (r, g, b) = match (h as u8) % 6 {
+            0 => (c, x, 0.),
+            1 => (x, c, 0.),
+            2 => (0., c, x),
+            3 => (0., x, c),
+            4 => (x, 0., c),
+            _ => (c, 0., x),
+        };
+
+        let (r, g, b) = (r + m, g + m, b + m);
+
+        match mode {
+            GamutMode::Clip => (cuwf::<T>(r), cuwf::<T>(g), cuwf::<T>(b)).into(),
+            GamutMode::Preserve => RGBColor::raw(cuwf::<T>(r), cuwf::<T>(g), cuwf::<T>(b)),
+            GamutMode::Rescale => {
+                let max = r.max(g).max(b);
+                if max > 1.0 {
+                    RGBColor::raw(cuwf::<T>(r / max), cuwf::<T>(g / max), cuwf::<T>(b / max))
+                } else {
+                    RGBColor::raw(cuwf::<T>(r), cuwf::<T>(g), cuwf::<T>(b))
+                }
+            }
+        }
+    }
+
+    /// Converts the channels of this color into another type
+    #[inline]
+    pub fn conv<H2: Channel, T2: Channel>(self) -> HSLColor<H2, T2, S> {
+        HSLColor {
+            h: self.h.conv(),
+            s: self.s.conv(),
+            l: self.l.conv(),
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<H: Channel, T: Channel, S> Color for HSLColor<H, T, S> {
+    /// Normalize the color's values by normalizing the hue and zeroing the unnecessary channels
+    ///
+    /// If lightness channel is zero or at it's max, saturation and hue are also zeroed, since
+    /// black and white have no hue or saturation.
+    fn normalize(self) -> Self {
+        let (h, s, l) = self.tuple();
+        if l == T::ch_zero() || l == T::ch_max() || s == T::ch_zero() {
+            HSLColor {
+                h: H::ch_zero(),
+                s: T::ch_zero(),
+                l: l.clamp(),
+                _space: PhantomData,
+            }
+        } else {
+            HSLColor {
+                h: h.clamp(),
+                s: s.clamp(),
+                l: l.clamp(),
+                _space: PhantomData,
+            }
+        }
+    }
+
+    fn is_normal(&self) -> bool {
+        let (h, s, l) = (&self.h, &self.s, &self.l);
+        let (h0, t0) = (H::ch_zero(), T::ch_zero());
+
+        if !h.in_range() || !s.in_range() || !l.in_range() {
+            false
+        } else if *l == t0 || *l == T::ch_max() {
+            *h == h0 && *s == t0
+        } else if *s == t0 {
+            *h == h0
+        } else {
+            true
+        }
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HSLColor<H, T, SRGBSpace>
+where
+    Self: Color,
+{
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        use self::BaseColor::*;
+
+        let f = |h: f32, s: f32, l: f32| Self::new(Deg(h).conv::<H>(), s.conv(), l.conv());
+
+        match base_color {
+            Black => f(0.0, 0.0, 0.0),
+            Grey => f(0.0, 0.0, 0.5),
+            White => f(0.0, 0.0, 1.0),
+            Red => f(0.0, 1.0, 0.5),
+            Yellow => f(60.0, 1.0, 0.5),
+            Green => f(120.0, 1.0, 0.5),
+            Cyan => f(180.0, 1.0, 0.5),
+            Blue => f(240.0, 1.0, 0.5),
+            Magenta => f(300.0, 1.0, 0.5),
+        }
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HSLColor<H, T, LinearSpace> {
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        RGBColor::<f32, LinearSpace>::from(base_color)
+            .hsl::<H>()
+            .conv()
+    }
+}
+
+impl<H: Channel, T: Channel, S> From<RGBColor<T, S>> for HSLColor<H, T, S>
+where
+    Self: Color,
+{
+    fn from(rgb: RGBColor<T, S>) -> Self {
+        rgb.hsl()
+    }
+}
+
+impl<H: Channel, T: Channel, S> Default for HSLColor<H, T, S> {
+    fn default() -> Self {
+        HSLColor {
+            h: H::ch_zero(),
+            s: T::ch_zero(),
+            l: T::ch_zero(),
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<H: Clone, T: Clone, S> Clone for HSLColor<H, T, S> {
+    fn clone(&self) -> Self {
+        HSLColor {
+            h: self.h.clone(),
+            s: self.s.clone(),
+            l: self.l.clone(),
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<H: Copy, T: Copy, S> Copy for HSLColor<H, T, S> {}
+
+impl<S> fmt::Display for HSLColor<f32, f32, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>5.1}°,{:>5.1}%,{:>5.1}%",
+            self.h,
+            self.s * 100.0,
+            self.l * 100.0
+        )
+    }
+}
+
+impl<H: Channel + Copy, T: Channel, S> Hue for HSLColor<H, T, S> {
+    type Hue = H;
+
+    #[inline]
+    fn get_hue(&self) -> H {
+        self.h
+    }
+
+    fn shift_hue(self, degrees: f32) -> Self {
+        let (h, s, l) = self.tuple();
+        let h = h.conv::<Deg<f32>>() + Deg(degrees);
+        HSLColor::new(h.conv::<H>(), s, l)
+    }
+}
+
+impl<H: Channel, T: Channel, S> Saturate for HSLColor<H, T, S> {
+    fn saturate(self, factor: f32) -> Self {
+        let (h, s, l) = self.tuple();
+        let s = cuwtf(s);
+        HSLColor::new(h, cuwf(s + (1.0 - s) * factor), l)
+    }
+
+    fn desaturate(self, factor: f32) -> Self {
+        let (h, s, l) = self.tuple();
+        let s = cuwtf(s);
+        HSLColor::new(h, cuwf(s * (1.0 - factor)), l)
+    }
+}
+
+impl<H: Channel, T: Channel, S> Shade for HSLColor<H, T, S> {
+    fn lighten(self, factor: f32) -> Self {
+        let (h, s, l) = self.tuple();
+        let l = cuwtf(l);
+        HSLColor::new(h, s, cuwf(l + (1.0 - l) * factor))
+    }
+
+    fn darken(self, factor: f32) -> Self {
+        let (h, s, l) = self.tuple();
+        let l = cuwtf(l);
+        HSLColor::new(h, s, cuwf(l * (1.0 - factor)))
+    }
+}
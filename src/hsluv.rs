@@ -0,0 +1,229 @@
+//! The HSLuv and CIELCHuv color spaces
+//!
+//! These are cylindrical representations built on top of CIE Luv, chosen because equal steps in
+//! saturation and lightness look roughly equally-uniform to the human eye, unlike HSV's hue.
+
+use crate::*;
+
+// the D65 white point's u' and v' chromaticities, used by CIELUV
+const WHITE_U: f32 = 0.19783000664283681;
+const WHITE_V: f32 = 0.46831999493879100;
+
+// CIE standard constants relating L* to Y
+const CIE_EPSILON: f32 = 0.0088564516790356308;
+const CIE_KAPPA: f32 = 903.2962962962963;
+
+// rows of the XYZ-to-linear-sRGB matrix (D65), used to bound the sRGB gamut in the Luv plane
+const XYZ_TO_LINEAR_RGB: [[f32; 3]; 3] = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.9692660, 1.8760108, 0.0415560],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+
+fn y_from_l(l: f32) -> f32 {
+    if l <= CIE_EPSILON * CIE_KAPPA {
+        l / CIE_KAPPA
+    } else {
+        ((l + 16.0) / 116.0).powi(3)
+    }
+}
+
+/// The six straight lines (one per RGB primary, clipped at 0 and at 1) bounding the sRGB gamut
+/// in the Luv chroma plane at the given lightness.
+///
+/// Each line is returned as `(m, b)`, satisfying `length(hue) = b / (sin(hue) - m*cos(hue))`.
+fn gamut_bounds(l: f32) -> [(f32, f32); 6] {
+    let sub2 = y_from_l(l);
+
+    let mut bounds = [(0.0, 0.0); 6];
+    let mut i = 0;
+    for &[m1, m2, m3] in &XYZ_TO_LINEAR_RGB {
+        for &t in &[0.0, 1.0] {
+            let top1 = (284517.0 * m1 - 94839.0 * m3) * sub2;
+            let top2 =
+                (838422.0 * m3 + 769860.0 * m2 + 731718.0 * m1) * l * sub2 - 769860.0 * t * l;
+            let bottom = (632260.0 * m3 - 126452.0 * m2) * sub2 + 126452.0 * t;
+
+            bounds[i] = (top1 / bottom, top2 / bottom);
+            i += 1;
+        }
+    }
+    bounds
+}
+
+/// The largest chroma that stays inside the sRGB gamut for the given lightness and hue.
+///
+/// `l` is in `[0, 100]` and `hue` is in radians.
+fn max_chroma(l: f32, hue: f32) -> f32 {
+    if l <= 0.0 || l >= 100.0 {
+        return 0.0;
+    }
+
+    let (sin, cos) = hue.sin_cos();
+
+    gamut_bounds(l)
+        .iter()
+        .filter_map(|&(m, b)| {
+            let length = b / (sin - m * cos);
+            if length >= 0.0 {
+                Some(length)
+            } else {
+                None
+            }
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+/// A color in the CIELChuv colorspace: CIE Luv expressed in cylindrical lightness/chroma/hue form
+///
+/// `L` is in `[0, 100]`, `C` is an unbounded chroma and `H` is the hue angle.
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct LChuvColor<H> {
+    pub l: f32,
+    pub c: f32,
+    pub h: H,
+}
+
+impl<H> LChuvColor<H> {
+    /// Creates a new LChuv color from the given lightness, chroma and hue.
+    #[inline]
+    pub fn new<H2: Into<H>>(l: f32, c: f32, h: H2) -> Self {
+        LChuvColor { l, c, h: h.into() }
+    }
+
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (f32, f32, H) {
+        (self.l, self.c, self.h)
+    }
+}
+
+impl<H: Channel> LChuvColor<H> {
+    /// Converts this color into linear CIE Luv.
+    fn luv(self) -> (f32, f32, f32) {
+        let hue_rad = cuwtf(self.h.conv::<Rad>());
+        let (sin, cos) = hue_rad.sin_cos();
+        (self.l, self.c * cos, self.c * sin)
+    }
+
+    /// Converts this color into the CIE XYZ space.
+    fn xyz(self) -> (f32, f32, f32) {
+        let (l, u, v) = self.luv();
+
+        if l <= 0.0 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let u_prime = u / (13.0 * l) + WHITE_U;
+        let v_prime = v / (13.0 * l) + WHITE_V;
+
+        let y = y_from_l(l);
+        let x = y * 9.0 * u_prime / (4.0 * v_prime);
+        let z = y * (12.0 - 3.0 * u_prime - 20.0 * v_prime) / (4.0 * v_prime);
+
+        (x, y, z)
+    }
+
+    /// Converts this color into sRGB, clamping any out-of-gamut channels.
+    ///
+    /// `C` is unbounded, so a chroma beyond what the sRGB gamut holds at this lightness and hue
+    /// gives linear RGB channels outside `[0, 1]`, which are clamped channel by channel.
+    pub fn rgb<T: Channel + num_traits::Float>(self) -> RGBColor<T, SRGBSpace> {
+        let (x, y, z) = self.xyz();
+
+        let [m1, m2, m3] = XYZ_TO_LINEAR_RGB;
+        let r = m1[0] * x + m1[1] * y + m1[2] * z;
+        let g = m2[0] * x + m2[1] * y + m2[2] * z;
+        let b = m3[0] * x + m3[1] * y + m3[2] * z;
+
+        let linear: RGBColor<f32, LinearSpace> = (r, g, b).into();
+        linear.std_encode().conv::<T>()
+    }
+
+    /// Converts this color into HSLuv.
+    pub fn hsluv(self) -> HSLuvColor<H> {
+        let max_c = max_chroma(self.l, cuwtf(self.h.conv::<Rad>()));
+        let s = if max_c <= 0.0 { 0.0 } else { self.c / max_c * 100.0 };
+        HSLuvColor { h: self.h, s, l: self.l }
+    }
+}
+
+/// A perceptually-uniform cylindrical color in the HSLuv space
+///
+/// `L` and `S` are both in `[0, 100]`, and `H` is the hue angle. Every combination of channels
+/// maps to a displayable sRGB color.
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct HSLuvColor<H> {
+    pub h: H,
+    pub s: f32,
+    pub l: f32,
+}
+
+impl<H> HSLuvColor<H> {
+    /// Creates a new HSLuv color from the given hue, saturation and lightness.
+    #[inline]
+    pub fn new<H2: Into<H>>(h: H2, s: f32, l: f32) -> Self {
+        HSLuvColor { h: h.into(), s, l }
+    }
+
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (H, f32, f32) {
+        (self.h, self.s, self.l)
+    }
+}
+
+impl<H: Channel> HSLuvColor<H> {
+    /// Converts this color into CIELChuv.
+    pub fn lchuv(self) -> LChuvColor<H> {
+        let max_c = max_chroma(self.l, cuwtf(self.h.conv::<Rad>()));
+        let c = max_c * self.s / 100.0;
+        LChuvColor { l: self.l, c, h: self.h }
+    }
+
+    /// Converts this color into sRGB, clamping any out-of-gamut channels.
+    #[inline]
+    pub fn rgb<T: Channel + num_traits::Float>(self) -> RGBColor<T, SRGBSpace> {
+        self.lchuv().rgb()
+    }
+}
+
+impl<H: Channel> Color for HSLuvColor<H> {
+    fn normalize(self) -> Self {
+        HSLuvColor {
+            h: self.h.clamp(),
+            s: self.s.max(0.0).min(100.0),
+            l: self.l.max(0.0).min(100.0),
+        }
+    }
+
+    fn is_normal(&self) -> bool {
+        self.h.in_range() && self.s >= 0.0 && self.s <= 100.0 && self.l >= 0.0 && self.l <= 100.0
+    }
+}
+
+impl<H: Channel> Color for LChuvColor<H> {
+    fn normalize(self) -> Self {
+        LChuvColor {
+            l: self.l.max(0.0).min(100.0),
+            c: self.c.max(0.0),
+            h: self.h.clamp(),
+        }
+    }
+
+    fn is_normal(&self) -> bool {
+        self.h.in_range() && self.c >= 0.0 && self.l >= 0.0 && self.l <= 100.0
+    }
+}
+
+impl<H: Channel> Default for HSLuvColor<H> {
+    fn default() -> Self {
+        HSLuvColor { h: H::ch_zero(), s: 0.0, l: 0.0 }
+    }
+}
+
+impl<H: Channel> Default for LChuvColor<H> {
+    fn default() -> Self {
+        LChuvColor { l: 0.0, c: 0.0, h: H::ch_zero() }
+    }
+}
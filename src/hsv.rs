@@ -49,11 +49,21 @@ where
 }
 
 impl<H: Channel, T: Channel, S> HSVColor<H, T, S> {
-    /// Transform this color into RGB form
+    /// Transform this color into RGB form, clipping any out-of-range channels
     ///
-    /// This should be done to a normalized HSV color.
+    /// This should be done to a normalized HSV color. Equivalent to
+    /// `rgb_with(GamutMode::Clip)`.
+    #[inline]
     pub fn rgb(self) -> RGBColor<T, S> {
-        let h = cuwtf(self.h.conv::<Deg<f32>>()) / 60.0;
+        self.rgb_with(GamutMode::Clip)
+    }
+
+    /// Transform this color into RGB form, handling out-of-range channels with `mode`
+    ///
+    /// The hue is wrapped into its normal range before conversion, so this never panics even on
+    /// a non-normalized color.
+    pub fn rgb_with(self, mode: GamutMode) -> RGBColor<T, S> {
+        let h = cuwtf(self.h.conv::<Deg<f32>>().wrap()) / 60.0;
         let (s, v) = (cuwtf(self.s), cuwtf(self.v));
 
         // largest, second largest and the smallest component
@@ -61,17 +71,30 @@ impl<H: Channel, T: Channel, S> HSVColor<H, T, S> {
         let xc = mc * (1.0 - (h % 2.0 - 1.0).abs());
         let min = v - mc;
 
-        let (r, g, b) = match h as u8 {
+        // `h` is now guaranteed to be in `[0, 6)`, but the modulo keeps this total regardless
+        let (r, g, b) = match (h as u8) % 6 {
             0 => (mc, xc, 0.),
             1 => (xc, mc, 0.),
             2 => (0., mc, xc),
             3 => (0., xc, mc),
             4 => (xc, 0., mc),
-            5 | 6 => (mc, 0., xc),
-            _ => panic!("Invalid hue value: {:?}", h),
+            _ => (mc, 0., xc),
         };
 
-        (cuwf::<T>(r + min), cuwf::<T>(g + min), cuwf::<T>(b + min)).into()
+        let (r, g, b) = (r + min, g + min, b + min);
+
+        match mode {
+            GamutMode::Clip => (cuwf::<T>(r), cuwf::<T>(g), cuwf::<T>(b)).into(),
+            GamutMode::Preserve => RGBColor::raw(cuwf::<T>(r), cuwf::<T>(g), cuwf::<T>(b)),
+            GamutMode::Rescale => {
+                let max = r.max(g).max(b);
+                if max > 1.0 {
+                    RGBColor::raw(cuwf::<T>(r / max), cuwf::<T>(g / max), cuwf::<T>(b / max))
+                } else {
+                    RGBColor::raw(cuwf::<T>(r), cuwf::<T>(g), cuwf::<T>(b))
+                }
+            }
+        }
     }
 
     /// Converts the channels of this color into another type
@@ -84,6 +107,16 @@ impl<H: Channel, T: Channel, S> HSVColor<H, T, S> {
             _space: PhantomData,
         }
     }
+
+    /// Converts this color into HWB, the whiteness/blackness form of HSV
+    ///
+    /// `W = (1 - S) * V`, `B = 1 - V`; the hue passes through unchanged.
+    pub fn hwb(self) -> HWBColor<H, T, S> {
+        let (h, s, v) = (self.h, cuwtf(self.s), cuwtf(self.v));
+        let whiteness = (1.0 - s) * v;
+        let blackness = 1.0 - v;
+        HWBColor::new(h, cuwf(whiteness), cuwf(blackness))
+    }
 }
 
 impl<H: Channel, T: Channel, S> Color for HSVColor<H, T, S> {
@@ -0,0 +1,275 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::*;
+
+/// A HWB color
+///
+/// HWB describes a hue mixed with a degree of whiteness and blackness, which tends to be more
+/// intuitive to tweak by hand than HSV's saturation/value pair.
+///
+/// ## Type arguments
+/// `H` is the type of hue channel, `T` is the type of the whiteness and blackness channels.
+///
+/// `S` is this color's colorspace.
+#[derive(Debug, PartialOrd, PartialEq)]
+pub struct HWBColor<H, T, S> {
+    pub h: H,
+    pub w: T,
+    pub b: T,
+    _space: PhantomData<S>,
+}
+
+impl<H, T, S> HWBColor<H, T, S> {
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (H, T, T) {
+        (self.h, self.w, self.b)
+    }
+    /// Deconstructs this color into an array of it's channels
+    #[inline]
+    pub fn array<U: From<H> + From<T>>(self) -> [U; 3] {
+        [self.h.into(), self.w.into(), self.b.into()]
+    }
+}
+
+impl<H, T, S> HWBColor<H, T, S>
+where
+    Self: Color,
+{
+    /// Create a new HWB value.
+    ///
+    /// The value is normalized on creation.
+    pub fn new<H2: Into<H>>(h: H2, w: T, b: T) -> Self {
+        HWBColor {
+            h: h.into(),
+            w,
+            b,
+            _space: PhantomData,
+        }
+        .normalize()
+    }
+}
+
+impl<H: Channel, T: Channel, S> HWBColor<H, T, S> {
+    /// Transform this color into RGB form, clipping any out-of-range channels
+    ///
+    /// This should be done to a normalized HWB color. Equivalent to
+    /// `rgb_with(GamutMode::Clip)`.
+    #[inline]
+    pub fn rgb(self) -> RGBColor<T, S> {
+        self.rgb_with(GamutMode::Clip)
+    }
+
+    /// Transform this color into RGB form, handling out-of-range channels with `mode`
+    ///
+    /// The hue is wrapped into its normal range before conversion, so this never panics even on
+    /// a non-normalized color. See `hsv` for the whiteness/blackness to saturation/value math.
+    #[inline]
+    pub fn rgb_with(self, mode: GamutMode) -> RGBColor<T, S> {
+        self.hsv().rgb_with(mode)
+    }
+
+    /// Converts this color into HSV
+    ///
+    /// `W` and `B` are rescaled down proportionally first if their sum exceeds `1`, then `V = 1 - B` and `S = 1 - W / V` (with `S = 0` when `V = 0`).
+    pub fn hsv(self) -> HSVColor<H, T, S> {
+        let (h, w, b) = self.tuple();
+        let (wf, bf) = (cuwtf(w), cuwtf(b));
+        let (wf, bf) = if wf + bf > 1.0 {
+            let sum = wf + bf;
+            (wf / sum, bf / sum)
+        } else {
+            (wf, bf)
+        };
+
+        let v = 1.0 - bf;
+        let s = if v == 0.0 { 0.0 } else { 1.0 - wf / v };
+
+        HSVColor::new(h, cuwf(s), cuwf(v))
+    }
+
+    /// Converts the channels of this color into another type
+    #[inline]
+    pub fn conv<H2: Channel, T2: Channel>(self) -> HWBColor<H2, T2, S> {
+        HWBColor {
+            h: self.h.conv(),
+            w: self.w.conv(),
+            b: self.b.conv(),
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<H: Channel, T: Channel, S> Color for HWBColor<H, T, S> {
+    /// Normalize the color's values by clamping the hue and rescaling whiteness/blackness
+    ///
+    /// If the whiteness and blackness sum to more than the channel's maximum, both are scaled
+    /// down proportionally until they don't. If they sum to exactly the maximum, the color is
+    /// fully achromatic (somewhere on the black-white line), so the hue is zeroed.
+    fn normalize(self) -> Self {
+        let (h, w, b) = self.tuple();
+        let max = cuwtf::<T>(T::ch_max());
+        let (wf, bf) = (cuwtf(w), cuwtf(b));
+        let sum = wf + bf;
+
+        let (w, b) = if sum > max {
+            (cuwf::<T>(wf * max / sum), cuwf::<T>(bf * max / sum))
+        } else {
+            (w.clamp(), b.clamp())
+        };
+
+        if cuwtf::<T>(w) + cuwtf::<T>(b) >= max {
+            HWBColor {
+                h: H::ch_zero(),
+                w,
+                b,
+                _space: PhantomData,
+            }
+        } else {
+            HWBColor {
+                h: h.clamp(),
+                w,
+                b,
+                _space: PhantomData,
+            }
+        }
+    }
+
+    fn is_normal(&self) -> bool {
+        let (h, w, b) = (&self.h, &self.w, &self.b);
+        let max = cuwtf::<T>(T::ch_max());
+        let sum = cuwtf::<T>(*w) + cuwtf::<T>(*b);
+
+        if !h.in_range() || !w.in_range() || !b.in_range() {
+            false
+        } else if sum > max {
+            false
+        } else if sum >= max {
+            *h == H::ch_zero()
+        } else {
+            true
+        }
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HWBColor<H, T, SRGBSpace>
+where
+    Self: Color,
+{
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        use self::BaseColor::*;
+
+        let f = |h: f32, w: f32, b: f32| Self::new(Deg(h).conv::<H>(), w.conv(), b.conv());
+
+        match base_color {
+            Black => f(0.0, 0.0, 1.0),
+            Grey => f(0.0, 0.5, 0.5),
+            White => f(0.0, 1.0, 0.0),
+            Red => f(0.0, 0.0, 0.0),
+            Yellow => f(60.0, 0.0, 0.0),
+            Green => f(120.0, 0.0, 0.0),
+            Cyan => f(180.0, 0.0, 0.0),
+            Blue => f(240.0, 0.0, 0.0),
+            Magenta => f(300.0, 0.0, 0.0),
+        }
+    }
+}
+
+impl<H: Channel, T: Channel> From<BaseColor> for HWBColor<H, T, LinearSpace> {
+    #[inline]
+    fn from(base_color: BaseColor) -> Self {
+        RGBColor::<f32, LinearSpace>::from(base_color)
+            .hwb::<H>()
+            .conv()
+    }
+}
+
+impl<H2, H, T, S> From<(H2, T, T)> for HWBColor<H, T, S>
+where
+    Self: Color,
+    H2: Into<H>,
+{
+    fn from(tuple: (H2, T, T)) -> Self {
+        let (h, w, b) = tuple;
+        HWBColor::new(h, w, b)
+    }
+}
+
+impl<H2, H, T, S> From<&(H2, T, T)> for HWBColor<H, T, S>
+where
+    Self: Color,
+    H2: Into<H> + Clone,
+    T: Clone,
+{
+    fn from(tuple: &(H2, T, T)) -> Self {
+        let (h, w, b) = tuple.clone();
+        HWBColor::new(h, w, b)
+    }
+}
+
+impl<U, H, T, S> From<[U; 3]> for HWBColor<H, T, S>
+where
+    Self: Color,
+    U: Clone + Into<H> + Into<T>,
+{
+    fn from(array: [U; 3]) -> Self {
+        Self::new(
+            array[0].clone(),
+            array[1].clone().into(),
+            array[2].clone().into(),
+        )
+    }
+}
+
+impl<U, H, T, S> From<&[U; 3]> for HWBColor<H, T, S>
+where
+    Self: Color,
+    U: Clone + Into<H> + Into<T>,
+{
+    fn from(array: &[U; 3]) -> Self {
+        Self::new(
+            array[0].clone(),
+            array[1].clone().into(),
+            array[2].clone().into(),
+        )
+    }
+}
+
+impl<H: Channel, T: Channel, S> Default for HWBColor<H, T, S> {
+    fn default() -> Self {
+        HWBColor {
+            h: H::ch_zero(),
+            w: T::ch_zero(),
+            b: T::ch_max(),
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<H: Clone, T: Clone, S> Clone for HWBColor<H, T, S> {
+    fn clone(&self) -> Self {
+        HWBColor {
+            h: self.h.clone(),
+            w: self.w.clone(),
+            b: self.b.clone(),
+            _space: PhantomData,
+        }
+    }
+}
+
+impl<H: Copy, T: Copy, S> Copy for HWBColor<H, T, S> {}
+
+// TODO make more generic
+impl<S> fmt::Display for HWBColor<f32, f32, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>5.1}°,{:>5.1}%,{:>5.1}%",
+            self.h,
+            self.w * 100.0,
+            self.b * 100.0
+        )
+    }
+}
@@ -1,5 +1,4 @@
 use std::iter::{IntoIterator, ExactSizeIterator, FusedIterator};
-use std::mem;
 
 use crate::{Alpha, RGBColor};
 
@@ -19,9 +18,12 @@ impl<T> IntoIter<T> {
         }
     }
 
-    fn from3(x: T, y: T, z: T) -> Self {
+    fn from3(x: T, y: T, z: T) -> Self
+    where T: Clone {
+        // there's no fourth value to put in the leading slot, so it's padded with a clone of `x`
+        // instead of zeroed memory; it's never read since `idx` starts past it
         IntoIter {
-            array: [ unsafe { mem::zeroed() }, x, y, z],
+            array: [x.clone(), x, y, z],
             idx: 1
         }
     }
@@ -0,0 +1,399 @@
+//! The CIELAB and CIELCh color spaces, plus the CIEDE2000 perceptual difference metric
+//!
+//! These build on CIE XYZ. Distances in Lab roughly correspond to perceived differences, which
+//! lets `delta_e` give a perceptual color-matching metric that raw RGB or HSV distance can't.
+
+use crate::*;
+
+// the D65 white point in CIE XYZ
+const WHITE_X: f32 = 0.95047;
+const WHITE_Y: f32 = 1.0;
+const WHITE_Z: f32 = 1.08883;
+
+// the linear-sRGB-to-XYZ matrix (D65), and its inverse
+const LINEAR_RGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.4124, 0.3576, 0.1805],
+    [0.2126, 0.7152, 0.0722],
+    [0.0193, 0.1192, 0.9505],
+];
+const XYZ_TO_LINEAR_RGB: [[f32; 3]; 3] = [
+    [3.2406, -1.5372, -0.4986],
+    [-0.9689, 1.8758, 0.0415],
+    [0.0557, -0.2040, 1.0570],
+];
+
+/// A reference white point, given as CIE XYZ tristimulus values
+///
+/// Used by `XYZColor::adapt` to convert a color computed under one illuminant into the
+/// equivalent under another - e.g. a D65-sourced scan destined for a D50 print/ICC profile.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WhitePoint {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl WhitePoint {
+    /// CIE standard illuminant D65 (daylight, ~6504 K) - the white point this module's RGB/XYZ/Lab
+    /// matrices already assume
+    pub const D65: WhitePoint = WhitePoint { x: WHITE_X, y: WHITE_Y, z: WHITE_Z };
+
+    /// CIE standard illuminant D50 (horizon light, ~5003 K) - the usual print/ICC profile white
+    pub const D50: WhitePoint = WhitePoint { x: 0.96422, y: 1.0, z: 0.82521 };
+}
+
+// the Bradford cone-response matrix, and its inverse
+const BRADFORD: [[f32; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+const BRADFORD_INV: [[f32; 3]; 3] = [
+    [0.9869929, -0.1470543, 0.1599627],
+    [0.4323053, 0.5183603, 0.0492912],
+    [-0.0085287, 0.0400428, 0.9684867],
+];
+
+/// Multiplies a 3x3 matrix, given as rows, by a column vector
+fn mat_mul(m: [[f32; 3]; 3], v: (f32, f32, f32)) -> (f32, f32, f32) {
+    let [row0, row1, row2] = m;
+    let dot = |row: [f32; 3]| row[0] * v.0 + row[1] * v.1 + row[2] * v.2;
+    (dot(row0), dot(row1), dot(row2))
+}
+
+fn lab_f(t: f32) -> f32 {
+    if t > 0.008856 { t.cbrt() } else { 7.787 * t + 16.0 / 116.0 }
+}
+
+fn lab_f_inv(t: f32) -> f32 {
+    if t.powi(3) > 0.008856 { t.powi(3) } else { (t - 16.0 / 116.0) / 7.787 }
+}
+
+/// A color in the CIE 1931 XYZ colorspace, the device-independent basis Lab and LCh are built on
+///
+/// All three channels are unbounded relative to the D65 white point used throughout this module;
+/// `y` is nominally the relative luminance around `[0, 1]`, but an HDR source can push it higher,
+/// so `is_normal` doesn't enforce a range here.
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct XYZColor {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl XYZColor {
+    /// Creates a new XYZ color from the given tristimulus values.
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        XYZColor { x, y, z }
+    }
+
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (f32, f32, f32) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Converts this color into linear RGB, clamping any out-of-gamut channels.
+    pub fn rgb<T: Channel + num_traits::Float>(self) -> RGBColor<T, LinearSpace> {
+        let (x, y, z) = self.tuple();
+
+        let [m1, m2, m3] = XYZ_TO_LINEAR_RGB;
+        let r = m1[0] * x + m1[1] * y + m1[2] * z;
+        let g = m2[0] * x + m2[1] * y + m2[2] * z;
+        let b = m3[0] * x + m3[1] * y + m3[2] * z;
+
+        let linear: RGBColor<f32, LinearSpace> = (r, g, b).into();
+        linear.conv::<T>()
+    }
+
+    /// Converts this color into CIELAB.
+    pub fn lab(self) -> LabColor {
+        let (fx, fy, fz) = (lab_f(self.x / WHITE_X), lab_f(self.y / WHITE_Y), lab_f(self.z / WHITE_Z));
+        LabColor {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Adapts this color from one reference white point to another, using the Bradford method
+    ///
+    /// Transforms `from` and `to` into Bradford cone-response space, scales each cone response by
+    /// the ratio between the two, then transforms the result back. Call this before converting
+    /// between color spaces defined under different illuminants - e.g. adapting a D65 sRGB-derived
+    /// `XYZColor` to D50 before feeding it into a D50-referenced print profile.
+    pub fn adapt(self, from: WhitePoint, to: WhitePoint) -> XYZColor {
+        let src_cone = mat_mul(BRADFORD, (from.x, from.y, from.z));
+        let dst_cone = mat_mul(BRADFORD, (to.x, to.y, to.z));
+
+        let cone = mat_mul(BRADFORD, self.tuple());
+        let adapted_cone = (
+            cone.0 * dst_cone.0 / src_cone.0,
+            cone.1 * dst_cone.1 / src_cone.1,
+            cone.2 * dst_cone.2 / src_cone.2,
+        );
+
+        let (x, y, z) = mat_mul(BRADFORD_INV, adapted_cone);
+        XYZColor::new(x, y, z)
+    }
+}
+
+impl<T: Channel + num_traits::Float> From<RGBColor<T, LinearSpace>> for XYZColor {
+    fn from(rgb: RGBColor<T, LinearSpace>) -> Self {
+        let (r, g, b) = rgb.conv::<f32>().tuple();
+
+        let [m1, m2, m3] = LINEAR_RGB_TO_XYZ;
+        XYZColor {
+            x: m1[0] * r + m1[1] * g + m1[2] * b,
+            y: m2[0] * r + m2[1] * g + m2[2] * b,
+            z: m3[0] * r + m3[1] * g + m3[2] * b,
+        }
+    }
+}
+
+impl Color for XYZColor {
+    /// This space is unbounded, so there's nothing to renormalize; returns `self` unchanged.
+    fn normalize(self) -> Self {
+        self
+    }
+
+    /// Always true for finite tristimulus values, since this space has no fixed range
+    fn is_normal(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+}
+
+/// A color in the CIELAB colorspace
+///
+/// `l` is nominally `[0, 100]`, but like `XYZColor::y` can run higher for HDR sources; `a` and `b`
+/// are unbounded opponent-color axes, roughly green-red and blue-yellow. None of the three are
+/// range-clamped by `normalize`.
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct LabColor {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl LabColor {
+    /// Creates a new Lab color from the given lightness and opponent-color axes.
+    #[inline]
+    pub fn new(l: f32, a: f32, b: f32) -> Self {
+        LabColor { l, a, b }
+    }
+
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (f32, f32, f32) {
+        (self.l, self.a, self.b)
+    }
+
+    /// Converts this color into CIE XYZ.
+    pub fn xyz(self) -> XYZColor {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+        XYZColor::new(lab_f_inv(fx) * WHITE_X, lab_f_inv(fy) * WHITE_Y, lab_f_inv(fz) * WHITE_Z)
+    }
+
+    /// Converts this color into linear RGB, clamping any out-of-gamut channels.
+    #[inline]
+    pub fn rgb<T: Channel + num_traits::Float>(self) -> RGBColor<T, LinearSpace> {
+        self.xyz().rgb()
+    }
+
+    /// Converts this color into CIELCh, the polar form of Lab.
+    pub fn lch<H: Channel>(self) -> LchColor<H> {
+        let c = (self.a * self.a + self.b * self.b).sqrt();
+        let h = Rad(self.b.atan2(self.a)).wrap().conv::<H>();
+        LchColor { l: self.l, c, h }
+    }
+
+    /// Returns the perceptual color difference between this color and `other`, using CIEDE2000
+    ///
+    /// `0.0` means the colors are identical; larger values mean a more noticeable difference.
+    pub fn delta_e(&self, other: &Self) -> f32 {
+        ciede2000(*self, *other)
+    }
+
+    /// Same as `delta_e`; spells out which formula is used alongside `delta_e_76`
+    #[inline]
+    pub fn delta_e_2000(&self, other: &Self) -> f32 {
+        self.delta_e(other)
+    }
+
+    /// The plain Euclidean distance between this color and `other` in Lab space
+    ///
+    /// Much cheaper than `delta_e_2000`, but perceptually less accurate: it over- and
+    /// under-weights some hues relative to how noticeable a human actually finds the difference.
+    pub fn delta_e_76(&self, other: &Self) -> f32 {
+        let (l1, a1, b1) = self.tuple();
+        let (l2, a2, b2) = other.tuple();
+        ((l2 - l1).powi(2) + (a2 - a1).powi(2) + (b2 - b1).powi(2)).sqrt()
+    }
+
+    /// Same as `delta_e_76`; spells out which formula is used by its full name
+    #[inline]
+    pub fn delta_e_cie76(&self, other: &Self) -> f32 {
+        self.delta_e_76(other)
+    }
+}
+
+impl<T: Channel + num_traits::Float> From<RGBColor<T, LinearSpace>> for LabColor {
+    fn from(rgb: RGBColor<T, LinearSpace>) -> Self {
+        XYZColor::from(rgb).lab()
+    }
+}
+
+impl From<XYZColor> for LabColor {
+    fn from(xyz: XYZColor) -> Self {
+        xyz.lab()
+    }
+}
+
+impl Color for LabColor {
+    /// This space is unbounded, so there's nothing to renormalize; returns `self` unchanged.
+    fn normalize(self) -> Self {
+        self
+    }
+
+    /// Always true for finite channel values, since this space has no fixed range
+    fn is_normal(&self) -> bool {
+        self.l.is_finite() && self.a.is_finite() && self.b.is_finite()
+    }
+}
+
+/// A color in the CIELCh colorspace: CIELAB expressed in cylindrical lightness/chroma/hue form
+///
+/// `l` is nominally `[0, 100]` (unclamped, same as `LabColor::l`); `c` is an unbounded, always
+/// non-negative chroma; `h` is the hue angle, wrapped to its unit's range.
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct LchColor<H> {
+    pub l: f32,
+    pub c: f32,
+    pub h: H,
+}
+
+impl<H> LchColor<H> {
+    /// Creates a new LCh color from the given lightness, chroma and hue.
+    #[inline]
+    pub fn new<H2: Into<H>>(l: f32, c: f32, h: H2) -> Self {
+        LchColor { l, c, h: h.into() }
+    }
+
+    /// Deconstructs this color into a tuple of it's channels
+    #[inline]
+    pub fn tuple(self) -> (f32, f32, H) {
+        (self.l, self.c, self.h)
+    }
+}
+
+impl<H: Channel> LchColor<H> {
+    /// Converts this color into CIELAB.
+    pub fn lab(self) -> LabColor {
+        let hue_rad = cuwtf(self.h.conv::<Rad>());
+        let (sin, cos) = hue_rad.sin_cos();
+        LabColor::new(self.l, self.c * cos, self.c * sin)
+    }
+
+    /// Converts this color into linear RGB, clamping any out-of-gamut channels.
+    #[inline]
+    pub fn rgb<T: Channel + num_traits::Float>(self) -> RGBColor<T, LinearSpace> {
+        self.lab().rgb()
+    }
+
+    /// Returns the perceptual color difference between this color and `other`, using CIEDE2000
+    #[inline]
+    pub fn delta_e(&self, other: &Self) -> f32 {
+        self.lab().delta_e(&other.lab())
+    }
+}
+
+impl<H: Channel, T: Channel + num_traits::Float> From<RGBColor<T, LinearSpace>> for LchColor<H> {
+    fn from(rgb: RGBColor<T, LinearSpace>) -> Self {
+        LabColor::from(rgb).lch()
+    }
+}
+
+impl<H: Channel> Color for LchColor<H> {
+    /// Wraps the hue and floors negative chroma to zero; `l` is unbounded, so it's left as-is
+    fn normalize(self) -> Self {
+        LchColor { l: self.l, c: self.c.max(0.0), h: self.h.clamp() }
+    }
+
+    fn is_normal(&self) -> bool {
+        self.h.in_range() && self.c >= 0.0 && self.l.is_finite()
+    }
+}
+
+/// The CIEDE2000 color difference formula between two Lab colors
+///
+/// Ported directly from the formulas defined by the CIE; see `LabColor::delta_e`.
+fn ciede2000(lab1: LabColor, lab2: LabColor) -> f32 {
+    let (l1, a1, b1) = lab1.tuple();
+    let (l2, a2, b2) = lab2.tuple();
+
+    let (c1, c2) = ((a1 * a1 + b1 * b1).sqrt(), (a2 * a2 + b2 * b2).sqrt());
+    let c_bar = (c1 + c2) / 2.0;
+
+    let c_bar_pow7 = c_bar.powi(7);
+    let g = 0.5 * (1.0 - (c_bar_pow7 / (c_bar_pow7 + 25.0f32.powi(7))).sqrt());
+
+    let (a1, a2) = ((1.0 + g) * a1, (1.0 + g) * a2);
+    let (c1, c2) = ((a1 * a1 + b1 * b1).sqrt(), (a2 * a2 + b2 * b2).sqrt());
+
+    let hue = |a: f32, b: f32, c: f32| if c == 0.0 { 0.0 } else { b.atan2(a).to_degrees().rem_euclid(360.0) };
+    let (h1, h2) = (hue(a1, b1, c1), hue(a2, b2, c2));
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+
+    let delta_h_raw = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h_raw / 2.0).to_radians().sin();
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar_prime = (c1 + c2) / 2.0;
+
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0
+        - 0.17 * (h_bar - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_bar).to_radians().cos()
+        + 0.32 * (3.0 * h_bar + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_bar - 63.0).to_radians().cos();
+
+    let delta_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let c_bar_prime_pow7 = c_bar_prime.powi(7);
+    let r_c = 2.0 * (c_bar_prime_pow7 / (c_bar_prime_pow7 + 25.0f32.powi(7))).sqrt();
+
+    let s_l = 1.0 + (0.015 * (l_bar - 50.0).powi(2)) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar_prime;
+    let s_h = 1.0 + 0.015 * c_bar_prime * t;
+
+    let r_t = -(2.0 * delta_theta.to_radians()).sin() * r_c;
+
+    (
+        (delta_l / s_l).powi(2)
+            + (delta_c / s_c).powi(2)
+            + (delta_h / s_h).powi(2)
+            + r_t * (delta_c / s_c) * (delta_h / s_h)
+    )
+        .sqrt()
+}
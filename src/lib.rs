@@ -2,9 +2,25 @@ mod channel;
 mod base;
 mod alpha;
 mod rgb;
+mod packed;
 mod hsv;
+mod hsi;
+mod hsl;
+mod hwb;
+mod hsluv;
+mod lab;
 mod blend;
+mod gradient;
 mod iter;
+mod bytes;
+mod manipulate;
+mod bound;
+mod error;
+mod convert;
+mod contrast;
+mod css;
+mod distinct;
+mod quantize;
 
 pub mod space;
 
@@ -18,12 +34,27 @@ pub use channel::*;
 pub use base::*;
 pub use self::alpha::*;
 pub use rgb::*;
+pub use packed::*;
 pub use hsv::*;
+pub use hsi::*;
+pub use hsl::*;
+pub use hwb::*;
+pub use hsluv::*;
+pub use lab::*;
 pub use blend::*;
+pub use gradient::*;
 pub use iter::*;
+pub use bytes::*;
+pub use manipulate::*;
+pub use bound::*;
+pub use error::*;
+pub use convert::*;
+pub use contrast::*;
+pub use distinct::*;
+pub use quantize::*;
 
 use angle::*;
-use space::{LinearSpace, SRGBSpace, std_gamma_decode, std_gamma_encode};
+use space::{GamutMode, LinearSpace, SRGBSpace, ToneMapMode, std_gamma_decode, std_gamma_encode};
 
 /// A trait for colors
 pub trait Color: Sized {
@@ -167,13 +198,14 @@ pub fn ansi_bgcolor(color: SRGB24Color, text: &str) -> String {
     const CSI: &str = "\u{1B}[";
     let (r, g, b) = color.tuple();
 
-    // color the text as black or white depending on the bg:s lightness
-    let fg =
-        if color.conv::<f32>().std_decode().relative_luminance() < std_gamma_decode(0.5) {
-            format!("{}38;2;255;255;255m", CSI)
-        } else {
-            format!("{}38;2;;;m", CSI)
-        };
+    // color the text as whichever of black or white has the higher WCAG contrast against the bg
+    let bg = color.conv::<f32>().std_decode();
+    let white: LinRGBColor = BaseColor::White.into();
+    let fg = if *bg.best_contrast(&[BaseColor::Black.into(), white]) == white {
+        format!("{}38;2;255;255;255m", CSI)
+    } else {
+        format!("{}38;2;;;m", CSI)
+    };
 
     fg + &format!("{}48;2;{};{};{}m{}{0}0m", CSI, r, g, b, text)
 }
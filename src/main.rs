@@ -2,6 +2,7 @@ extern crate regex;
 #[macro_use] extern crate lazy_static;
 #[macro_use] extern crate failure;
 extern crate cgmath;
+extern crate colliberator;
 
 mod util;
 mod error;
@@ -0,0 +1,150 @@
+//! Cross-cutting color manipulation traits
+//!
+//! These operate on the cylindrical hue/saturation/shade representation of a color, regardless
+//! of which concrete type it's stored as.
+
+use crate::*;
+
+/// Trait for colors with a hue that can be read or rotated
+pub trait Hue {
+    /// The type of this color's hue channel
+    type Hue;
+
+    /// Returns this color's hue
+    fn get_hue(&self) -> Self::Hue;
+
+    /// Rotates this color's hue by the given amount of degrees
+    ///
+    /// The result wraps around, so eg. shifting by 360 degrees is a no-op.
+    fn shift_hue(self, degrees: f32) -> Self;
+}
+
+/// Trait for colors whose saturation can be raised or lowered
+pub trait Saturate: Sized {
+    /// Saturates this color by `factor`
+    ///
+    /// `factor` is a relative amount: `saturate(0.1)` moves the saturation 10% of the way
+    /// towards being fully saturated.
+    fn saturate(self, factor: f32) -> Self;
+
+    /// Desaturates this color by `factor`
+    ///
+    /// `factor` is a relative amount: `desaturate(0.1)` moves the saturation 10% of the way
+    /// towards being fully grey.
+    fn desaturate(self, factor: f32) -> Self;
+}
+
+/// Trait for colors that can be inverted to their visual opposite
+pub trait Complement: Sized {
+    /// Returns this color's complement
+    fn complement(&self) -> Self;
+}
+
+/// Trait for colors whose lightness can be raised or lowered
+pub trait Shade: Sized {
+    /// Lightens this color by `factor`
+    ///
+    /// `factor` is a relative amount: `lighten(0.1)` moves the lightness 10% of the way towards
+    /// being fully lit.
+    fn lighten(self, factor: f32) -> Self;
+
+    /// Darkens this color by `factor`
+    ///
+    /// `factor` is a relative amount: `darken(0.1)` moves the lightness 10% of the way towards
+    /// being fully dark.
+    fn darken(self, factor: f32) -> Self;
+}
+
+impl<H: Channel + Copy, T: Channel, S> Hue for HSVColor<H, T, S> {
+    type Hue = H;
+
+    #[inline]
+    fn get_hue(&self) -> H {
+        self.h
+    }
+
+    fn shift_hue(self, degrees: f32) -> Self {
+        let (h, s, v) = self.tuple();
+        let h = h.conv::<Deg<f32>>() + Deg(degrees);
+        HSVColor::new(h.conv::<H>(), s, v)
+    }
+}
+
+impl<H: Channel, T: Channel, S> Saturate for HSVColor<H, T, S> {
+    fn saturate(self, factor: f32) -> Self {
+        let (h, s, v) = self.tuple();
+        let s = cuwtf(s);
+        HSVColor::new(h, cuwf(s + (1.0 - s) * factor), v)
+    }
+
+    fn desaturate(self, factor: f32) -> Self {
+        let (h, s, v) = self.tuple();
+        let s = cuwtf(s);
+        HSVColor::new(h, cuwf(s * (1.0 - factor)), v)
+    }
+}
+
+impl<H: Channel, T: Channel, S> Shade for HSVColor<H, T, S> {
+    fn lighten(self, factor: f32) -> Self {
+        let (h, s, v) = self.tuple();
+        let v = cuwtf(v);
+        HSVColor::new(h, s, cuwf(v + (1.0 - v) * factor))
+    }
+
+    fn darken(self, factor: f32) -> Self {
+        let (h, s, v) = self.tuple();
+        let v = cuwtf(v);
+        HSVColor::new(h, s, cuwf(v * (1.0 - factor)))
+    }
+}
+
+impl<H: Channel + Copy, T: Channel + Copy, S> Complement for HSVColor<H, T, S> {
+    /// Rotates the hue by 180°, preserving saturation and value
+    fn complement(&self) -> Self {
+        (*self).shift_hue(180.0)
+    }
+}
+
+impl<T: Channel + Copy, S> Complement for RGBColor<T, S> {
+    /// The naive channel-wise negation `max - c`
+    ///
+    /// For the hue-correct complement (rotating hue instead of flattening it), convert through
+    /// `HSVColor` first.
+    fn complement(&self) -> Self {
+        let max = cuwtf::<T>(T::ch_max());
+        let f = |c: T| cuwf::<T>(max - cuwtf(c));
+        RGBColor::new(f(self.r), f(self.g), f(self.b))
+    }
+}
+
+impl<T: Channel + Copy, S> Hue for RGBColor<T, S> {
+    type Hue = Deg<f32>;
+
+    fn get_hue(&self) -> Deg<f32> {
+        (*self).hsv::<Deg<f32>>().h
+    }
+
+    fn shift_hue(self, degrees: f32) -> Self {
+        self.hsv::<Deg<f32>>().shift_hue(degrees).rgb()
+    }
+}
+
+impl<T: Channel + Copy, S> Saturate for RGBColor<T, S> {
+    fn saturate(self, factor: f32) -> Self {
+        self.hsv::<Deg<f32>>().saturate(factor).rgb()
+    }
+
+    fn desaturate(self, factor: f32) -> Self {
+        self.hsv::<Deg<f32>>().desaturate(factor).rgb()
+    }
+}
+
+impl<T: Channel + Copy, S> Shade for RGBColor<T, S> {
+    fn lighten(self, factor: f32) -> Self {
+        self.hsv::<Deg<f32>>().lighten(factor).rgb()
+    }
+
+    fn darken(self, factor: f32) -> Self {
+        self.hsv::<Deg<f32>>().darken(factor).rgb()
+    }
+}
@@ -0,0 +1,132 @@
+use crate::*;
+
+/// A color packed into a single `u32`, for compact storage in palettes or pixel buffers
+///
+/// Keeps every color at exactly 4 bytes, unlike the `f32`-channel color types. The channel
+/// layout is selected by which constructor/accessor pair is used, rather than by a separate
+/// runtime tag or type parameter, so a whole palette can be declared as plain `u32` literals.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PackedColor(pub u32);
+
+impl PackedColor {
+    /// Packs `r, g, b, a` into `0xRRGGBBAA`
+    #[inline]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        PackedColor((r as u32) << 24 | (g as u32) << 16 | (b as u32) << 8 | a as u32)
+    }
+
+    /// Packs `a, r, g, b` into `0xAARRGGBB`
+    #[inline]
+    pub const fn argb(a: u8, r: u8, g: u8, b: u8) -> Self {
+        PackedColor((a as u32) << 24 | (r as u32) << 16 | (g as u32) << 8 | b as u32)
+    }
+
+    /// Packs an opaque `r, g, b` into `0x00RRGGBB`, zeroing the unused high byte
+    #[inline]
+    pub const fn zrgb(r: u8, g: u8, b: u8) -> Self {
+        PackedColor((r as u32) << 16 | (g as u32) << 8 | b as u32)
+    }
+
+    /// Unpacks this color's channels, assuming it was packed with `rgba`
+    #[inline]
+    pub fn to_rgba(self) -> (u8, u8, u8, u8) {
+        let PackedColor(n) = self;
+        ((n >> 24) as u8, (n >> 16) as u8, (n >> 8) as u8, n as u8)
+    }
+
+    /// Unpacks this color's channels, assuming it was packed with `argb`
+    #[inline]
+    pub fn to_argb(self) -> (u8, u8, u8, u8) {
+        let PackedColor(n) = self;
+        ((n >> 24) as u8, (n >> 16) as u8, (n >> 8) as u8, n as u8)
+    }
+
+    /// Unpacks this color's channels, assuming it was packed with `zrgb`, ignoring the high byte
+    #[inline]
+    pub fn to_zrgb(self) -> (u8, u8, u8) {
+        let PackedColor(n) = self;
+        ((n >> 16) as u8, (n >> 8) as u8, n as u8)
+    }
+
+    /// This color's raw bits
+    #[inline]
+    pub const fn to_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Wraps a raw `u32` as-is; use `to_rgba`/`to_argb`/`to_zrgb` to interpret its bytes
+    #[inline]
+    pub const fn from_u32(n: u32) -> Self {
+        PackedColor(n)
+    }
+
+    /// This color's raw bits as little-endian bytes, for writing into a byte buffer
+    #[inline]
+    pub const fn to_le_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    /// Reads back a color previously written by `to_le_bytes`
+    #[inline]
+    pub const fn from_le_bytes(bytes: [u8; 4]) -> Self {
+        PackedColor(u32::from_le_bytes(bytes))
+    }
+
+    /// This color's alpha byte, assuming it was packed with `argb`
+    #[inline]
+    pub const fn a(self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    /// This color's red byte, assuming it was packed with `argb`
+    #[inline]
+    pub const fn r(self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// This color's green byte, assuming it was packed with `argb`
+    #[inline]
+    pub const fn g(self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// This color's blue byte, assuming it was packed with `argb`
+    #[inline]
+    pub const fn b(self) -> u8 {
+        self.0 as u8
+    }
+}
+
+impl From<SRGB24Color> for PackedColor {
+    /// Packs the color as fully opaque `0xRRGGBBFF`
+    #[inline]
+    fn from(color: SRGB24Color) -> Self {
+        let (r, g, b) = color.tuple();
+        PackedColor::rgba(r, g, b, 0xFF)
+    }
+}
+
+impl From<PackedColor> for SRGB24Color {
+    /// Unpacks the color, assuming it was packed with `rgba`, discarding the alpha byte
+    #[inline]
+    fn from(packed: PackedColor) -> Self {
+        let (r, g, b, _) = packed.to_rgba();
+        SRGB24Color::new(r, g, b)
+    }
+}
+
+impl From<SRGBA32Color> for PackedColor {
+    /// Packs the color as `0xAARRGGBB`
+    #[inline]
+    fn from(color: SRGBA32Color) -> Self {
+        PackedColor(color.to_u32(PixelFormat::Argb))
+    }
+}
+
+impl From<PackedColor> for SRGBA32Color {
+    /// Unpacks the color, assuming it was packed with `argb`
+    #[inline]
+    fn from(packed: PackedColor) -> Self {
+        SRGBA32Color::from_u32(packed.to_u32(), PixelFormat::Argb)
+    }
+}
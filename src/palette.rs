@@ -1,12 +1,15 @@
 use std::fmt;
 use std::path::Path;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, Write};
 use std::collections::BTreeMap;
 use std::iter::Iterator;
 
 use regex::Regex;
 
+use colliberator::{LabColor, RGBColor};
+use colliberator::space::LinearSpace;
+
 use error::PaletteError;
 use color::*;
 
@@ -15,6 +18,12 @@ lazy_static! {
     static ref COLORLINE_RE: Regex =    Regex::new(r"^\*\s*([^#]+?)\s*#([0-9a-fA-F]{6})").unwrap();
 }
 
+/// Converts a linear RGB color into CIELAB, via the `colliberator` library's Lab/XYZ pipeline.
+fn rgb_to_lab(rgb: LinRGBColor) -> LabColor {
+    let (r, g, b) = rgb.to_tuple();
+    RGBColor::<f32, LinearSpace>::new(r, g, b).into()
+}
+
 #[derive(Debug)]
 pub struct ColorSet {
     colors: Box<[SRGB24Color]>,
@@ -90,6 +99,87 @@ impl Palette {
     pub fn name_color<T: Color>(&self, color: T) -> Option<&str> {
         Some(self.colors.get(&color.srgb24())?.as_ref())
     }
+
+    /// Returns the name and CIEDE2000 distance of whichever entry is perceptually closest to
+    /// `color`, or `None` if the palette holds no colors at all.
+    ///
+    /// Unlike `name_color`, this always finds a match, even when `color` isn't an exact hit.
+    pub fn nearest_color<T: Color>(&self, color: T) -> Option<(&str, f32)> {
+        let lab = rgb_to_lab(color.lin_rgb());
+
+        self.colors
+            .iter()
+            .map(|(&rgb, name)| (name.as_ref(), lab.delta_e_2000(&rgb_to_lab(rgb.lin_rgb()))))
+            .min_by(|(_, d1), (_, d2)| d1.partial_cmp(d2).unwrap())
+    }
+
+    /// Writes this palette out in the GIMP `.gpl` palette format.
+    pub fn write_gpl<W: Write>(&self, mut out: W) -> io::Result<()> {
+        writeln!(out, "GIMP Palette")?;
+        writeln!(out, "Name: Colliberator export")?;
+        writeln!(out, "Columns: 0")?;
+        writeln!(out, "#")?;
+
+        for (color, name) in &self.colors {
+            let (r, g, b) = color.to_tuple();
+            writeln!(out, "{:>3} {:>3} {:>3}\t{}", r, g, b, name)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a flat 16-entry RGB byte block suitable for a Linux virtual console color map.
+    ///
+    /// If `colorset_name` names a colorset with exactly 16 colors, those are used directly, in
+    /// file order. Otherwise the 16 entries are chosen from the whole palette by nearest-color
+    /// quantization: greedily picking whichever remaining color is farthest, by CIEDE2000, from
+    /// those already picked. A palette with fewer than 16 colors gives fewer than 16 entries.
+    pub fn write_vt_cmap<W: Write>(&self, colorset_name: &str, mut out: W) -> io::Result<()> {
+        const VT_COLORS: usize = 16;
+
+        let colors = self.colorsets.iter()
+            .find(|(name, _)| &**name == colorset_name)
+            .filter(|(_, set)| set.colors.len() == VT_COLORS)
+            .map(|(_, set)| set.colors.to_vec())
+            .unwrap_or_else(|| self.quantize_16());
+
+        for color in colors {
+            let (r, g, b) = color.to_tuple();
+            out.write_all(&[r, g, b])?;
+        }
+        Ok(())
+    }
+
+    /// Picks up to 16 of this palette's colors, maximizing the minimum CIEDE2000 distance between
+    /// any two picks, via greedy farthest-point placement.
+    fn quantize_16(&self) -> Vec<SRGB24Color> {
+        let candidates: Vec<(SRGB24Color, LabColor)> = self.colors.keys()
+            .map(|&color| (color, rgb_to_lab(color.lin_rgb())))
+            .collect();
+
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+
+        let (first_color, first_lab) = candidates[0];
+        let mut result = vec![first_color];
+        let mut chosen = vec![first_lab];
+
+        while result.len() < 16 && result.len() < candidates.len() {
+            let &(color, lab) = candidates.iter()
+                .filter(|(color, _)| !result.contains(color))
+                .max_by(|(_, a), (_, b)| {
+                    let min_a = chosen.iter().map(|l| l.delta_e_2000(a)).fold(f32::INFINITY, f32::min);
+                    let min_b = chosen.iter().map(|l| l.delta_e_2000(b)).fold(f32::INFINITY, f32::min);
+                    min_a.partial_cmp(&min_b).unwrap()
+                })
+                .unwrap();
+
+            result.push(color);
+            chosen.push(lab);
+        }
+
+        result
+    }
 }
 
 pub struct ColorSetsIter<'a>(
@@ -0,0 +1,125 @@
+//! Median-cut color quantization, for building an indexed palette out of a pixel buffer
+//!
+//! Unlike `distinct_colors`, which generates a palette out of thin air, this derives one from an
+//! actual image: pixels start in one box spanning their RGB bounding volume, and the box with the
+//! widest channel is repeatedly split at its median along that channel until there are `n` boxes.
+//! Each box's representative color is the channel-wise mean of its members.
+
+use crate::*;
+
+#[derive(Clone, Copy)]
+enum Channel {
+    R,
+    G,
+    B,
+}
+
+fn channel_value(pixel: SRGB24Color, channel: Channel) -> u8 {
+    match channel {
+        Channel::R => pixel.r,
+        Channel::G => pixel.g,
+        Channel::B => pixel.b,
+    }
+}
+
+/// Returns the channel with the widest value spread across `indices`, and that spread
+fn widest_channel(pixels: &[SRGB24Color], indices: &[usize]) -> (Channel, u8) {
+    let (mut r_min, mut g_min, mut b_min) = (u8::max_value(), u8::max_value(), u8::max_value());
+    let (mut r_max, mut g_max, mut b_max) = (0u8, 0u8, 0u8);
+
+    for &i in indices {
+        let (r, g, b) = pixels[i].tuple();
+        r_min = r_min.min(r);
+        r_max = r_max.max(r);
+        g_min = g_min.min(g);
+        g_max = g_max.max(g);
+        b_min = b_min.min(b);
+        b_max = b_max.max(b);
+    }
+
+    let spreads = [
+        (Channel::R, r_max - r_min),
+        (Channel::G, g_max - g_min),
+        (Channel::B, b_max - b_min),
+    ];
+    spreads
+        .iter()
+        .copied()
+        .max_by_key(|&(_, spread)| spread)
+        .unwrap()
+}
+
+/// The channel-wise mean color of the pixels at `indices`
+fn box_mean(pixels: &[SRGB24Color], indices: &[usize]) -> SRGB24Color {
+    let (mut r_sum, mut g_sum, mut b_sum) = (0u32, 0u32, 0u32);
+
+    for &i in indices {
+        let (r, g, b) = pixels[i].tuple();
+        r_sum += u32::from(r);
+        g_sum += u32::from(g);
+        b_sum += u32::from(b);
+    }
+
+    let n = indices.len() as u32;
+    SRGB24Color::new((r_sum / n) as u8, (g_sum / n) as u8, (b_sum / n) as u8)
+}
+
+/// Quantizes `pixels` down to at most `n` representative colors
+///
+/// Returns the palette, and a parallel array of indices into it, one per input pixel. Nearest
+/// palette entries are chosen by Lab `delta_e`, since Euclidean RGB distance doesn't track
+/// perceived difference well.
+///
+/// If `pixels` is empty, both returned collections are empty. A box holding a single pixel isn't
+/// split further, so with fewer than `n` pixels the palette ends up with fewer than `n` entries.
+/// A box of identical pixels can still be split, so the palette may repeat a color.
+pub fn median_cut(pixels: &[SRGB24Color], n: usize) -> (Vec<SRGB24Color>, Vec<usize>) {
+    if pixels.is_empty() || n == 0 {
+        return (Vec::new(), Vec::new());
+    }
+
+    let mut boxes: Vec<Vec<usize>> = vec![(0..pixels.len()).collect()];
+
+    while boxes.len() < n {
+        let (split_i, _) = boxes
+            .iter()
+            .enumerate()
+            .map(|(i, indices)| (i, widest_channel(pixels, indices).1))
+            .max_by_key(|&(_, spread)| spread)
+            .unwrap();
+
+        if boxes[split_i].len() < 2 {
+            break;
+        }
+
+        let mut indices = boxes.swap_remove(split_i);
+        let (channel, _) = widest_channel(pixels, &indices);
+        indices.sort_by_key(|&i| channel_value(pixels[i], channel));
+
+        let half = indices.len() / 2;
+        let upper = indices.split_off(half);
+        boxes.push(indices);
+        boxes.push(upper);
+    }
+
+    let palette: Vec<SRGB24Color> = boxes.iter().map(|indices| box_mean(pixels, indices)).collect();
+    let palette_lab: Vec<LabColor> = palette
+        .iter()
+        .map(|&c| LabColor::from(c.conv::<f32>().std_decode()))
+        .collect();
+
+    let pixel_indices = pixels
+        .iter()
+        .map(|&pixel| {
+            let lab = LabColor::from(pixel.conv::<f32>().std_decode());
+            palette_lab
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| lab.delta_e(a).partial_cmp(&lab.delta_e(b)).unwrap())
+                .map(|(i, _)| i)
+                .unwrap()
+        })
+        .collect();
+
+    (palette, pixel_indices)
+}
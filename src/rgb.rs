@@ -6,10 +6,20 @@ use num_traits::Float;
 
 use crate::*;
 
+/// The byte order used when packing/unpacking a color into a single integer
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum PixelFormat {
+    /// Alpha in the highest byte, followed by red, green and blue
+    Argb,
+    /// Red in the highest byte, followed by green, blue and alpha
+    Rgba,
+}
+
 /// An RGB color
 ///
 /// `T` is the type of this color's channels, and `S` is this color's colorspace.
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+#[repr(C)]
 pub struct RGBColor<T, S> {
     pub r: T,
     pub g: T,
@@ -56,6 +66,31 @@ impl<T: Channel, S> RGBColor<T, S> {
         .map(Channel::clamp)
     }
 
+    /// Creates a new RGB color without clamping the channels into range
+    ///
+    /// Used by conversions that want to inspect or rescale out-of-gamut values rather than
+    /// clipping them immediately, eg. `HSVColor::rgb_with`.
+    pub(crate) fn raw(r: T, g: T, b: T) -> Self {
+        RGBColor {
+            r,
+            g,
+            b,
+            _space: PhantomData,
+        }
+    }
+
+    /// Creates a new RGB color without clamping the channels into range
+    ///
+    /// For `LinearSpace` colors, this is the entry point for HDR values above `1.0`. The
+    /// `Add`/`Mul`/`Div` impls build their results through `new` and so clamp them back into
+    /// range; they do not keep over-range channels. `is_normal` still reports such a color as
+    /// out of range; call `tone_map` before `std_encode` to bring it back down to something
+    /// displayable.
+    #[inline]
+    pub fn new_unclamped(r: T, g: T, b: T) -> Self {
+        Self::raw(r, g, b)
+    }
+
     /// Converts the channels of this color into another type
     #[inline]
     pub fn conv<U: Channel>(self) -> RGBColor<U, S> {
@@ -106,20 +141,28 @@ impl<S> RGBColor<u8, S> {
         }
     }
 
-    /// Create 24-bit RGB color from a 6 or 3 character hexcode, returning `None` if unsuccesful.
+    /// Parses a 24-bit RGB color from a `#RGB` or `#RRGGBB` hex string
     ///
-    /// Same as `from_hex_unchecked` except returns `None` if the input is not valid or too short.
-    pub fn from_hex<T: AsRef<str>>(hex_str: T) -> Option<Self> {
-        let len = hex_str.as_ref().len();
-        let mut h = hex_str.as_ref().bytes().map(|b| {
-            let mut b = b;
+    /// The leading `#` is optional.
+    pub fn from_hex<T: AsRef<str>>(hex_str: T) -> Result<Self, PaletteError> {
+        let text = hex_str.as_ref();
+        let invalid = || PaletteError::InvalidHex { text: text.into() };
+
+        let digits = text.strip_prefix('#').unwrap_or(text);
+        let len = digits.len();
+
+        if len != 3 && len != 6 {
+            return Err(invalid());
+        }
+
+        let mut h = digits.bytes().map(|mut b| {
             b.make_ascii_lowercase();
             b
         });
 
         let mut f = || -> Option<u8> {
             u8::from_str_radix(
-                str::from_utf8(&if len >= 6 {
+                str::from_utf8(&if len == 6 {
                     [h.next()?, h.next()?]
                 } else {
                     let x = h.next()?;
@@ -131,7 +174,42 @@ impl<S> RGBColor<u8, S> {
             .ok()
         };
 
-        Some((f()?, f()?, f()?).into())
+        match (f(), f(), f()) {
+            (Some(r), Some(g), Some(b)) => Ok((r, g, b).into()),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Formats this color as a `#RRGGBB` hex string
+    #[inline]
+    pub fn to_hex_string(self) -> String {
+        format!("#{:X}", self)
+    }
+
+    /// Packs this color's channels into a single `u32`, in the given byte order
+    ///
+    /// Since a plain RGB color has no alpha channel, it is always packed/read as fully opaque
+    /// (`0xFF`).
+    pub fn to_u32(self, order: PixelFormat) -> u32 {
+        Alpha::new(self, 0xFFu8).to_u32(order)
+    }
+
+    /// Unpacks a `u32` in the given byte order into an RGB color, discarding any alpha byte
+    #[inline]
+    pub fn from_u32(n: u32, order: PixelFormat) -> Self {
+        Alpha::<Self, u8>::from_u32(n, order).color
+    }
+
+    /// Packs this color into a `0xAARRGGBB` integer, with the alpha byte set to `0xFF`
+    #[inline]
+    pub fn to_u32_argb(self) -> u32 {
+        self.to_u32(PixelFormat::Argb)
+    }
+
+    /// Unpacks a color from a `0xAARRGGBB` integer, discarding the alpha byte
+    #[inline]
+    pub fn from_u32_argb(n: u32) -> Self {
+        Self::from_u32(n, PixelFormat::Argb)
     }
 }
 
@@ -158,6 +236,56 @@ impl<T: Channel, S> RGBColor<T, S> {
 
         HSVColor::new(hue.conv::<H>(), saturation.conv(), value.conv())
     }
+
+    pub fn hsl<H: Channel>(self) -> HSLColor<H, T, S> {
+        let (r, g, b) = self.map(Channel::conv::<f32>).tuple();
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let lightness = (max + min) / 2.0;
+        let saturation = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        let hue = Deg(60.0
+            * if delta == 0.0 {
+                0.0
+            } else if max == r {
+                ((g - b) / delta) % 6.0
+            } else if max == g {
+                (b - r) / delta + 2.0
+            } else {
+                (r - g) / delta + 4.0
+            });
+
+        HSLColor::new(hue.conv::<H>(), saturation.conv(), lightness.conv())
+    }
+
+    pub fn hwb<H: Channel>(self) -> HWBColor<H, T, S> {
+        let (r, g, b) = self.map(Channel::conv::<f32>).tuple();
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let whiteness = min;
+        let blackness = 1.0 - max;
+        let hue = Deg(60.0
+            * if delta == 0.0 {
+                0.0
+            } else if max == r {
+                ((g - b) / delta) % 6.0
+            } else if max == g {
+                (b - r) / delta + 2.0
+            } else {
+                (r - g) / delta + 4.0
+            });
+
+        HWBColor::new(hue.conv::<H>(), whiteness.conv(), blackness.conv())
+    }
 }
 
 impl<T: Float + Channel> RGBColor<T, SRGBSpace> {
@@ -187,6 +315,53 @@ impl<T: Float + Channel> RGBColor<T, LinearSpace> {
         let (r, g, b) = self.tuple();
         cuwf::<T>(0.2126) * r + cuwf::<T>(0.7152) * g + cuwf::<T>(0.0722) * b
     }
+
+    /// The WCAG contrast ratio between this color and `other`
+    ///
+    /// Ranges from `1.0` (no contrast) to `21.0` (black against white), and is symmetric: the
+    /// lighter of the two colors is always used as the numerator.
+    pub fn contrast_ratio(&self, other: &Self) -> T {
+        let (l1, l2) = (self.relative_luminance(), other.relative_luminance());
+        let (lighter, darker) = if l1 > l2 { (l1, l2) } else { (l2, l1) };
+        (lighter + cuwf(0.05)) / (darker + cuwf(0.05))
+    }
+
+    /// Returns whichever of `candidates` has the highest contrast ratio against this color
+    ///
+    /// Panics if `candidates` is empty.
+    pub fn best_contrast<'a>(&self, candidates: &'a [Self]) -> &'a Self {
+        candidates
+            .iter()
+            .max_by(|a, b| {
+                self.contrast_ratio(a)
+                    .partial_cmp(&self.contrast_ratio(b))
+                    .unwrap()
+            })
+            .expect("candidates must not be empty")
+    }
+
+    /// Inverts this color's perceived lightness while preserving hue and chroma
+    ///
+    /// Round-trips through CIELAB, flipping `l` to `100.0 - l`, so a dark saturated color becomes
+    /// a light one of the same hue rather than washing out like a naive RGB negation would. Handy
+    /// for picking a readable label color over an arbitrary background.
+    pub fn invert_luma(self) -> Self {
+        let lab = LabColor::from(self);
+        LabColor::new(100.0 - lab.l, lab.a, lab.b).rgb()
+    }
+
+    /// Compresses this HDR color's channels down into the displayable `[0, 1]` range
+    ///
+    /// Unlike clamping, this preserves relative differences between bright highlights instead of
+    /// flattening them all to the same clipped value. Call this (then `std_encode`) on colors
+    /// built from `new_unclamped`.
+    pub fn tone_map(self, mode: ToneMapMode) -> Self {
+        let one = cuwf::<T>(1.0);
+        self.map(|c| match mode {
+            ToneMapMode::Reinhard => c / (one + c),
+            ToneMapMode::Exposure(exposure) => one - (-c * cuwf::<T>(exposure)).exp(),
+        })
+    }
 }
 
 impl<T: Channel, S> Default for RGBColor<T, S> {
@@ -14,6 +14,32 @@ pub struct SRGBSpace;
 #[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct LinearSpace;
 
+/// How to handle channel values that fall outside of a color space's displayable gamut
+///
+/// Used by conversions that can produce out-of-range channels, eg. going from a cylindrical
+/// color back into RGB.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub enum GamutMode {
+    /// Clamp each channel independently into its valid range
+    Clip,
+    /// Leave the channels as they are, even if outside of their valid range
+    Preserve,
+    /// Divide every channel by the largest one when it exceeds the valid range, preserving hue
+    Rescale,
+}
+
+/// How to compress an unbounded HDR linear color down into the displayable `[0, 1]` range
+///
+/// Used by `RGBColor::tone_map` before gamma-encoding to sRGB, as an alternative to clipping
+/// highlights outright.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ToneMapMode {
+    /// The Reinhard operator, `c / (1 + c)`
+    Reinhard,
+    /// Exposure-based compression, `1 - exp(-c * exposure)`
+    Exposure(f32),
+}
+
 /// Gamma encode a linear color channel into the sRGB space
 pub fn std_gamma_encode<T: Float>(linear: T) -> T {
     const SRGB_CUTOFF: f32 = 0.0031308;
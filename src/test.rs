@@ -70,6 +70,65 @@ fn hex_conversion_3char() {
     }
 }
 
+#[test]
+fn hex_string_and_u32_packing() {
+    let rgb = SRGB24Color::new(0x11, 0x22, 0x33);
+    let rgba = rgb.with_alpha(0x44u8);
+
+    assert_eq!(rgb.to_hex_string(), "#112233");
+    assert_eq!(SRGB24Color::from_hex("#112233").unwrap(), rgb);
+    assert_eq!(SRGB24Color::from_hex("123").unwrap(), SRGB24Color::new(0x11, 0x22, 0x33));
+    assert!(SRGB24Color::from_hex("nope").is_err());
+
+    assert_eq!(rgba.to_hex_string(), "#11223344");
+    assert_eq!(Alpha::<SRGB24Color, u8>::from_hex("#11223344").unwrap(), rgba);
+
+    assert_eq!(rgb.to_u32_argb(), 0xFF11_2233);
+    assert_eq!(SRGB24Color::from_u32_argb(0xFF11_2233), rgb);
+    assert_eq!(rgba.to_u32_argb(), 0x4411_2233);
+    assert_eq!(Alpha::<SRGB24Color, u8>::from_u32_argb(0x4411_2233), rgba);
+
+    assert_eq!(rgba.to_u32_rgba(), 0x1122_3344);
+    assert_eq!(Alpha::<SRGB24Color, u8>::from_u32_rgba(0x1122_3344), rgba);
+}
+
+#[test]
+fn from_into_color_hub_routing() {
+    let rgb = SRGBColor::new(0.5, 0.25, 0.75);
+    let hsv: StdHSVColor = rgb.into_color();
+    let rgb2: SRGBColor = hsv.into_color();
+
+    let (r1, g1, b1) = rgb.tuple();
+    let (r2, g2, b2) = rgb2.tuple();
+    assert!((r1 - r2).abs() < 1e-4 && (g1 - g2).abs() < 1e-4 && (b1 - b2).abs() < 1e-4);
+
+    let lin: LinRGBColor = rgb.into_color();
+    let lin2: LinRGBColor = lin.into_color_unclamped();
+    assert_eq!(lin, lin2);
+
+    let lab: LabColor = hsv.into_color();
+    let hsv2: StdHSVColor = lab.into_color();
+    let (h1, s1, v1) = hsv.tuple();
+    let (h2, s2, v2) = hsv2.tuple();
+    assert!((h1.0 - h2.0).abs() < 1e-2 && (s1 - s2).abs() < 1e-2 && (v1 - v2).abs() < 1e-2);
+
+    let xyz: XYZColor = rgb.into_color();
+    let (r1, g1, b1) = lin.tuple();
+    let (r2, g2, b2) = xyz.rgb::<f32>().tuple();
+    assert!((r1 - r2).abs() < 1e-3 && (g1 - g2).abs() < 1e-3 && (b1 - b2).abs() < 1e-3);
+}
+
+#[test]
+fn rgb_to_hsi_and_back() {
+    let rgb = SRGBColor::new(0.6, 0.2, 0.4);
+    let hsi = HSIColor::<Deg<f32>, f32, SRGBSpace>::from(rgb);
+    let rgb2 = hsi.rgb();
+
+    let (r1, g1, b1) = rgb.tuple();
+    let (r2, g2, b2) = rgb2.tuple();
+    assert!((r1 - r2).abs() < 1e-4 && (g1 - g2).abs() < 1e-4 && (b1 - b2).abs() < 1e-4);
+}
+
 #[test]
 fn into_iterator() {
     let c1 = SRGBAColor::new((0.25, 0.5, 1.0), 0.9);
@@ -89,6 +148,20 @@ fn into_iterator() {
     assert_eq!(i2.next(), None);
 }
 
+#[test]
+fn pod_byte_view() {
+    let mut colors = [
+        SRGB24Color::new(0x11, 0x22, 0x33),
+        SRGB24Color::new(0x44, 0x55, 0x66),
+    ];
+
+    assert_eq!(as_bytes(&colors), &[0x11, 0x22, 0x33, 0x44, 0x55, 0x66]);
+    assert_eq!(from_bytes::<SRGB24Color>(as_bytes(&colors)), &colors);
+
+    as_bytes_mut(&mut colors)[0] = 0xFF;
+    assert_eq!(colors[0], SRGB24Color::new(0xFF, 0x22, 0x33));
+}
+
 #[test]
 fn angle_conversion() {
     use std::f32::consts::PI;
@@ -100,7 +173,424 @@ fn angle_conversion() {
         assert_eq!(deg.0, f * 360.0);
         assert_eq!(rad.0, f * 2.0 * PI);
         assert_eq!(deg.0.round(), rad.conv::<Deg<f32>>().0.round());
+
+        // the same pivot is also reachable through From/Into, not just Channel::conv
+        let deg_via_into: Deg<f32> = rad.into();
+        assert_eq!(deg.0.round(), deg_via_into.0.round());
+
+        let rev: Rev<f32> = deg.into();
+        assert_eq!(rev.0.round(), f.round());
+        let deg_via_rev: Deg<f32> = rev.into();
+        assert_eq!(deg.0.round(), deg_via_rev.0.round());
     }
+
+    assert_eq!(Deg::<f32>::half_turn(), Deg(180.0));
+    assert_eq!(Rad::half_turn().0, PI);
+
+    assert_eq!(Deg(30.0).opposite(), Deg(210.0));
+    assert_eq!(Deg(200.0).opposite(), Deg(20.0));
+}
+
+#[test]
+fn hsv_rgb_gamut_modes() {
+    // fields are mutated directly so the color is never renormalized, to exercise an
+    // out-of-range value and a hue well outside of [0, 360)
+    let mut over = StdHSVColor::new(5.0, 1.0, 1.0);
+    over.v = 1.2;
+    over.h = Deg(365.0);
+
+    let clipped = over.rgb_with(GamutMode::Clip);
+    let preserved = over.rgb_with(GamutMode::Preserve);
+    let rescaled = over.rgb_with(GamutMode::Rescale);
+
+    assert!(clipped.tuple().0 <= 1.0);
+    assert!(preserved.tuple().0 > 1.0);
+    assert!(rescaled.tuple().0 <= 1.0);
+
+    // never panics, even on a wildly out-of-range hue
+    over.h = Deg(-1080.0);
+    over.rgb();
+}
+
+#[test]
+fn hdr_tone_mapping() {
+    // additive light accumulation via the `Add` impl can push channels above 1.0
+    let bright = LinRGBColor::new_unclamped(2.0, 0.5, 0.0) + LinRGBColor::new(0.0, 0.0, 0.3);
+    assert!(bright.tuple().0 > 1.0);
+    assert!(!bright.is_normal());
+
+    let reinhard = bright.tone_map(ToneMapMode::Reinhard);
+    assert_eq!(reinhard.tuple().0, 2.0 / 3.0);
+    assert!(reinhard.tuple().0 < 1.0);
+
+    let exposed = bright.tone_map(ToneMapMode::Exposure(1.0));
+    assert_eq!(exposed.tuple().0, 1.0 - (-2.0f32).exp());
+    assert!(exposed.tuple().0 < 1.0);
+}
+
+#[test]
+fn alpha_wrapper() {
+    let opaque = SRGB24Color::new(10, 20, 30);
+    let translucent = opaque.with_alpha(128u8);
+
+    assert_eq!(translucent.r, 10);
+    assert_eq!(translucent.without_alpha(), opaque);
+}
+
+#[test]
+fn gradient_interpolation() {
+    let black = LinRGBColor::new(0.0, 0.0, 0.0);
+    let white = LinRGBColor::new(1.0, 1.0, 1.0);
+    let gradient = Gradient::new(vec![(0.0, black), (1.0, white)]);
+
+    assert_eq!(gradient.at(0.0), black);
+    assert_eq!(gradient.at(1.0), white);
+    assert_eq!(gradient.at(0.5).tuple(), (0.5, 0.5, 0.5));
+    assert_eq!(gradient.at(-1.0), black);
+    assert_eq!(gradient.sample(0.5), gradient.at(0.5));
+
+    let samples: Vec<_> = gradient.iter(3).collect();
+    assert_eq!(samples, vec![black, LinRGBColor::new(0.5, 0.5, 0.5), white]);
+    assert_eq!(gradient.take(3).collect::<Vec<_>>(), samples);
+
+    let srgb_samples: Vec<_> = gradient.take_srgb24(3).collect();
+    assert_eq!(srgb_samples, vec![
+        SRGB24Color::new(0, 0, 0),
+        LinRGBColor::new(0.5, 0.5, 0.5).std_encode().conv(),
+        SRGB24Color::new(255, 255, 255),
+    ]);
+
+    let red = StdHSVColor::new(0.0, 1.0, 1.0);
+    let blue = StdHSVColor::new(240.0, 1.0, 1.0);
+    let hue_gradient = Gradient::new(vec![(0.0, red), (1.0, blue)]);
+    // the shorter arc from 0 to 240 goes backwards through 360, not forwards through 120
+    assert!((hue_gradient.at(0.5).tuple().0.0 - 300.0).abs() < 1e-3);
+
+    let opaque = black.with_alpha(1.0f32);
+    let transparent = white.with_alpha(0.0f32);
+    let mid = opaque.lerp(transparent, 0.5);
+    assert_eq!(mid.color.tuple(), (0.5, 0.5, 0.5));
+    assert_eq!(mid.alpha, 0.5);
+}
+
+#[test]
+fn distinct_color_palette() {
+    let palette = distinct_colors(8);
+    assert_eq!(palette.len(), 8);
+
+    for (i, &a) in palette.iter().enumerate() {
+        for &b in &palette[i + 1..] {
+            let lab_a = LabColor::from(a.conv::<f32>().std_decode());
+            let lab_b = LabColor::from(b.conv::<f32>().std_decode());
+            assert!(lab_a.delta_e(&lab_b) > 0.0);
+        }
+    }
+}
+
+#[test]
+fn median_cut_quantization() {
+    let pixels = vec![
+        SRGB24Color::new(255, 0, 0),
+        SRGB24Color::new(250, 5, 5),
+        SRGB24Color::new(0, 255, 0),
+        SRGB24Color::new(5, 250, 5),
+        SRGB24Color::new(0, 0, 255),
+        SRGB24Color::new(5, 5, 250),
+    ];
+
+    let (palette, indices) = median_cut(&pixels, 3);
+    assert_eq!(palette.len(), 3);
+    assert_eq!(indices.len(), pixels.len());
+
+    // the two near-red pixels should land on the same palette entry, and it should be reddish
+    assert_eq!(indices[0], indices[1]);
+    let (r, g, b) = palette[indices[0]].tuple();
+    assert!(r > g && r > b);
+
+    // requesting more colors than unique inputs shouldn't panic, it just falls short of n
+    let (small_palette, _) = median_cut(&pixels, 100);
+    assert!(small_palette.len() <= pixels.len());
+
+    let (empty_palette, empty_indices) = median_cut(&[], 4);
+    assert!(empty_palette.is_empty() && empty_indices.is_empty());
+}
+
+#[test]
+fn wcag_contrast() {
+    let black = LinRGBColor::from(BaseColor::Black);
+    let white = LinRGBColor::from(BaseColor::White);
+
+    assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-5);
+    assert_eq!(black.contrast_ratio(&black), 1.0);
+
+    let grey = LinRGBColor::from(BaseColor::Grey);
+    assert_eq!(*black.best_contrast(&[grey, white]), white);
+}
+
+#[test]
+fn generic_contrast_and_srgb_lerp() {
+    let black = StdHSVColor::new(0.0, 0.0, 0.0);
+    let white = StdHSVColor::new(0.0, 0.0, 1.0);
+
+    assert!((black.wcag_contrast_ratio(white) - 21.0).abs() < 1e-5);
+    assert_eq!(black.luma(), 0.0);
+    assert_eq!(white.luma(), 1.0);
+
+    let grey = StdHSVColor::new(0.0, 0.0, 0.5);
+    assert_eq!(black.best_contrast(grey, white), white);
+
+    // interpolating sRGB directly would darken the midpoint; routing through the hub avoids that
+    let srgb_black = SRGBColor::new(0.0, 0.0, 0.0);
+    let srgb_white = SRGBColor::new(1.0, 1.0, 1.0);
+    let mid = srgb_black.lerp(srgb_white, 0.5);
+    assert_eq!(mid, LinRGBColor::new(0.5, 0.5, 0.5).std_encode());
+}
+
+#[test]
+fn packed_color_layouts() {
+    const PALETTE: [PackedColor; 2] = [PackedColor::rgba(0x11, 0x22, 0x33, 0xFF), PackedColor::zrgb(0x44, 0x55, 0x66)];
+
+    assert_eq!(PALETTE[0].to_u32(), 0x1122_33FF);
+    assert_eq!(PALETTE[0].to_rgba(), (0x11, 0x22, 0x33, 0xFF));
+    assert_eq!(PALETTE[1].to_zrgb(), (0x44, 0x55, 0x66));
+
+    let rgb = SRGB24Color::new(0x11, 0x22, 0x33);
+    let packed: PackedColor = rgb.into();
+    assert_eq!(packed, PackedColor::rgba(0x11, 0x22, 0x33, 0xFF));
+    assert_eq!(SRGB24Color::from(packed), rgb);
+
+    let argb = PackedColor::argb(0xAA, 0x11, 0x22, 0x33);
+    assert_eq!((argb.a(), argb.r(), argb.g(), argb.b()), (0xAA, 0x11, 0x22, 0x33));
+    assert_eq!(PackedColor::from_le_bytes(argb.to_le_bytes()), argb);
+
+    let rgba: SRGBA32Color = Alpha::new(rgb, 0xAA);
+    let packed_rgba: PackedColor = rgba.into();
+    assert_eq!(packed_rgba, argb);
+    assert_eq!(SRGBA32Color::from(packed_rgba), rgba);
+}
+
+#[test]
+fn css_color_parsing() {
+    use std::str::FromStr;
+
+    let expected = SRGB24Color::new(0x11, 0x22, 0x33);
+
+    assert_eq!(SRGB24Color::from_str("#112233").unwrap(), expected);
+    assert_eq!(SRGB24Color::from_str("#112233FF").unwrap(), expected);
+    assert_eq!(SRGB24Color::from_str("rgb(17, 34, 51)").unwrap(), expected);
+    assert_eq!(SRGB24Color::from_str("  RGB(17 34 51)  ").unwrap(), expected);
+
+    assert_eq!(SRGB24Color::from_str("red").unwrap(), SRGB24Color::new(0xFF, 0, 0));
+    assert_eq!(SRGB24Color::from_str("ReD").unwrap(), SRGB24Color::new(0xFF, 0, 0));
+
+    let hsl_red = SRGB24Color::from_str("hsl(0, 100%, 50%)").unwrap();
+    assert_eq!(hsl_red, SRGB24Color::new(0xFF, 0, 0));
+
+    assert_eq!(SRGB24Color::from_str("rebeccapurple").unwrap(), SRGB24Color::new(0x66, 0x33, 0x99));
+
+    assert!(SRGB24Color::from_str("not-a-color").is_err());
+
+    // alpha carries through on the Alpha type, and defaults to fully opaque when absent
+    type RGBA = Alpha<SRGB24Color, u8>;
+    let expected_rgba = expected.with_alpha(0x44u8);
+
+    assert_eq!(RGBA::from_str("#11223344").unwrap(), expected_rgba);
+    assert_eq!(RGBA::from_str("#1234").unwrap(), Alpha::new(SRGB24Color::new(0x11, 0x22, 0x33), 0x44));
+    assert_eq!(RGBA::from_str("rgba(17, 34, 51, 0.2667)").unwrap(), expected_rgba);
+    assert_eq!(RGBA::from_str("hsla(0, 100%, 50%, 50%)").unwrap(), Alpha::new(SRGB24Color::new(0xFF, 0, 0), 0x7F));
+    assert_eq!(RGBA::from_str("#112233").unwrap(), expected.with_alpha(0xFFu8));
+}
+
+#[test]
+fn rgb_to_hsl_and_back() {
+    let rgb = SRGBColor::new(0.6, 0.2, 0.4);
+    let hsl = rgb.hsl::<Deg<f32>>();
+    let rgb2 = hsl.rgb();
+
+    let (r1, g1, b1) = rgb.tuple();
+    let (r2, g2, b2) = rgb2.tuple();
+    assert!((r1 - r2).abs() < 1e-4 && (g1 - g2).abs() < 1e-4 && (b1 - b2).abs() < 1e-4);
+
+    let lightened = hsl.lighten(1.0);
+    assert_eq!(lightened.tuple().2, 1.0);
+
+    let desaturated = hsl.desaturate(1.0);
+    assert_eq!(desaturated.tuple().1, 0.0);
+}
+
+#[test]
+fn rgb_to_hwb_and_back() {
+    let rgb = SRGBColor::new(0.6, 0.2, 0.4);
+    let hwb = rgb.hwb::<Deg<f32>>();
+    let rgb2 = hwb.rgb();
+
+    let (r1, g1, b1) = rgb.tuple();
+    let (r2, g2, b2) = rgb2.tuple();
+    assert!((r1 - r2).abs() < 1e-4 && (g1 - g2).abs() < 1e-4 && (b1 - b2).abs() < 1e-4);
+
+    // white and black are both fully achromatic, so their hue is canonicalized to zero
+    let white = HWBColor::<Deg<f32>, f32, SRGBSpace>::new(123.0, 1.0, 0.0);
+    assert_eq!(white.tuple().0, Deg(0.0));
+
+    // whiteness and blackness summing past 1.0 are rescaled down proportionally
+    let over = HWBColor::<Deg<f32>, f32, SRGBSpace>::new(0.0, 0.8, 0.8);
+    let (_, w, b) = over.tuple();
+    assert!((w - 0.5).abs() < 1e-6 && (b - 0.5).abs() < 1e-6);
+
+    // HSV <-> HWB follows W = (1-S)*V, B = 1-V directly, not just by round-tripping through RGB
+    let hsv = StdHSVColor::new(120.0, 0.4, 0.8);
+    let hwb_from_hsv = hsv.hwb();
+    let (_, w, b) = hwb_from_hsv.tuple();
+    assert!((w - 0.48).abs() < 1e-6 && (b - 0.2).abs() < 1e-6);
+    let hsv2 = hwb_from_hsv.hsv();
+    assert!((hsv.s - hsv2.s).abs() < 1e-6 && (hsv.v - hsv2.v).abs() < 1e-6);
+}
+
+#[test]
+fn rgb_to_lab_and_back() {
+    let rgb = LinRGBColor::new(0.6, 0.2, 0.4);
+    let lab = LabColor::from(rgb);
+    let rgb2: LinRGBColor = lab.rgb();
+
+    let (r1, g1, b1) = rgb.tuple();
+    let (r2, g2, b2) = rgb2.tuple();
+    assert!((r1 - r2).abs() < 1e-3 && (g1 - g2).abs() < 1e-3 && (b1 - b2).abs() < 1e-3);
+
+    let lch: LchColor<Deg<f32>> = lab.lch();
+    let lab2 = lch.lab();
+    assert!((lab.a - lab2.a).abs() < 1e-3 && (lab.b - lab2.b).abs() < 1e-3);
+
+    assert_eq!(lab.delta_e(&lab), 0.0);
+    assert!(lab.delta_e(&LabColor::new(0.0, 0.0, 0.0)) > 0.0);
+
+    let xyz = XYZColor::from(rgb);
+    let lab3 = xyz.lab();
+    assert!((lab.l - lab3.l).abs() < 1e-3 && (lab.a - lab3.a).abs() < 1e-3 && (lab.b - lab3.b).abs() < 1e-3);
+    assert!(xyz.is_normal());
+
+    assert_eq!(lab.delta_e_2000(&lab), lab.delta_e(&lab));
+    assert_eq!(lab.delta_e_76(&lab), 0.0);
+    assert!(lab.delta_e_76(&LabColor::new(0.0, 0.0, 0.0)) > 0.0);
+    assert_eq!(lab.delta_e_cie76(&lab), lab.delta_e_76(&lab));
+}
+
+#[test]
+fn lab_xyz_unbounded() {
+    // an HDR-range XYZ/Lab color is still "normal": these spaces have no fixed range to clamp to
+    let hdr_xyz = XYZColor::new(0.5, 1.8, 0.5);
+    assert!(hdr_xyz.is_normal());
+    assert_eq!(hdr_xyz.normalize().tuple(), hdr_xyz.tuple());
+
+    let hdr_lab = LabColor::new(140.0, -20.0, 20.0);
+    assert!(hdr_lab.is_normal());
+    assert_eq!(hdr_lab.normalize().tuple(), hdr_lab.tuple());
+
+    // chroma is still floored at zero and hue still wrapped, even though `l` is left alone
+    let lch = LchColor::new(140.0, -5.0, Deg(400.0));
+    let normalized = lch.normalize();
+    assert_eq!(normalized.l, 140.0);
+    assert_eq!(normalized.c, 0.0);
+    assert_eq!(normalized.h, Deg(40.0));
+}
+
+#[test]
+fn bradford_chromatic_adaptation() {
+    // adapting a white point to itself is a no-op
+    let white_d65 = XYZColor::new(WhitePoint::D65.x, WhitePoint::D65.y, WhitePoint::D65.z);
+    let unchanged = white_d65.adapt(WhitePoint::D65, WhitePoint::D65);
+    assert!((white_d65.x - unchanged.x).abs() < 1e-5);
+    assert!((white_d65.y - unchanged.y).abs() < 1e-5);
+    assert!((white_d65.z - unchanged.z).abs() < 1e-5);
+
+    // the D65 white point, adapted to D50, lands on the D50 white point
+    let adapted = white_d65.adapt(WhitePoint::D65, WhitePoint::D50);
+    assert!((adapted.x - WhitePoint::D50.x).abs() < 1e-4);
+    assert!((adapted.y - WhitePoint::D50.y).abs() < 1e-4);
+    assert!((adapted.z - WhitePoint::D50.z).abs() < 1e-4);
+
+    // adapting there and back returns the original color
+    let rgb = LinRGBColor::new(0.6, 0.2, 0.4);
+    let xyz = XYZColor::from(rgb);
+    let round_tripped = xyz.adapt(WhitePoint::D65, WhitePoint::D50).adapt(WhitePoint::D50, WhitePoint::D65);
+    assert!((xyz.x - round_tripped.x).abs() < 1e-4);
+    assert!((xyz.y - round_tripped.y).abs() < 1e-4);
+    assert!((xyz.z - round_tripped.z).abs() < 1e-4);
+}
+
+#[test]
+fn alpha_blend_over() {
+    let dst = LinRGBColor::new(0.0, 0.0, 0.0).with_alpha(1.0f32);
+    let src = LinRGBColor::new(1.0, 1.0, 1.0).with_alpha(0.5f32);
+
+    let out = dst.alpha_blend(&src);
+
+    assert_eq!(out.alpha, 1.0);
+    assert_eq!(out.color.tuple(), (0.5, 0.5, 0.5));
+
+    let transparent = Alpha::new(LinRGBColor::new(0.0, 0.0, 0.0), 0.0f32);
+    assert_eq!(transparent.alpha_blend(&transparent).alpha, 0.0);
+}
+
+#[test]
+fn premultiplied_alpha_round_trip_and_blend() {
+    let straight = Alpha::new(LinRGBColor::new(1.0, 0.5, 0.0), 0.5f32);
+    let premultiplied = straight.premultiply();
+    assert_eq!(premultiplied.color.tuple(), (0.5, 0.25, 0.0));
+    assert_eq!(premultiplied.alpha, 0.5);
+
+    let back = premultiplied.unmultiply();
+    assert_eq!(back.color.tuple(), straight.color.tuple());
+    assert_eq!(back.alpha, straight.alpha);
+
+    // fully transparent unmultiplies to zero channels instead of dividing by zero
+    let invisible = PremultipliedAlpha::new(LinRGBColor::new(0.0, 0.0, 0.0), 0.0f32);
+    assert_eq!(invisible.unmultiply().color.tuple(), (0.0, 0.0, 0.0));
+
+    // blending through the premultiplied path agrees with the straight-alpha path
+    let dst = LinRGBColor::new(0.0, 0.0, 0.0).with_alpha(1.0f32);
+    let src = LinRGBColor::new(1.0, 1.0, 1.0).with_alpha(0.5f32);
+    let via_straight = dst.alpha_blend(&src);
+    let via_premultiplied = dst.premultiply().alpha_blend(&src.premultiply()).unmultiply();
+    assert_eq!(via_straight.color.tuple(), via_premultiplied.color.tuple());
+    assert_eq!(via_straight.alpha, via_premultiplied.alpha);
+}
+
+#[test]
+fn channel_bounds() {
+    assert_eq!(StdHSVColor::bounds(), [(0.0, 360.0), (0.0, 1.0), (0.0, 1.0)]);
+    assert_eq!(LinRGB48Color::bounds(), [(0.0, 65535.0), (0.0, 65535.0), (0.0, 65535.0)]);
+
+    let [(l_min, l_max), (c_min, c_max), (h_min, h_max)] = LChuvColor::<Deg<f32>>::bounds();
+    assert_eq!((l_min, l_max), (0.0, 100.0));
+    assert_eq!((h_min, h_max), (0.0, 360.0));
+    assert!(c_max > c_min);
+}
+
+#[test]
+fn hue_saturate_shade() {
+    let red = StdHSVColor::new(0.0, 1.0, 1.0);
+
+    assert_eq!(red.get_hue(), Deg(0.0));
+    assert_eq!(red.shift_hue(120.0).get_hue(), Deg(120.0));
+
+    let grey = red.desaturate(1.0);
+    assert_eq!(grey.tuple().1, 0.0);
+
+    let dark = red.darken(1.0);
+    assert_eq!(dark.tuple().2, 0.0);
+}
+
+#[test]
+fn complement_and_invert_luma() {
+    let black = SRGB24Color::new(0, 0, 0);
+    assert_eq!(black.complement(), SRGB24Color::new(255, 255, 255));
+
+    let red = StdHSVColor::new(0.0, 1.0, 1.0);
+    assert_eq!(red.complement().tuple(), (Deg(180.0), 1.0, 1.0));
+
+    let dark_red = LinRGBColor::new(0.3, 0.0, 0.0);
+    let inverted = dark_red.invert_luma();
+    assert!(LabColor::from(inverted).l > LabColor::from(dark_red).l);
 }
 
 #[test]